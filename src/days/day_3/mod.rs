@@ -1,2 +0,0 @@
-mod errors;
-pub mod run;
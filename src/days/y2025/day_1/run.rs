@@ -1,28 +1,42 @@
-use crate::days::day_1::errors::{ParseDirectionError, ParseMoveError};
+use crate::days::y2025::day_1::errors::{ParseDirectionError, ParseMoveError};
 use crate::utils::advent_day::AdventDay;
-use crate::utils::load::load_from_file;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
 use anyhow::Result;
+use std::fmt;
+use std::fmt::Display;
 use std::str::FromStr;
 
 pub struct DayOne;
 
+inventory::submit! {
+    RegisteredDay::new(2025, 1, "Secret Entrance", &DayOne)
+}
+
 impl AdventDay for DayOne {
-    fn part_1(&self) -> Result<()> {
+    type Input = SafeCracker;
+
+    fn parse(&self, input: &str) -> Result<SafeCracker> {
+        let moves = input
+            .split_whitespace()
+            .map(|token| {
+                token
+                    .parse::<Move>()
+                    .map_err(|e| Diagnostic::new(input, token, format!("Invalid move: {}", e)))
+            })
+            .collect::<Result<Vec<Move>, Diagnostic>>()?;
+        Ok(SafeCracker::new(50, moves, 100))
+    }
+
+    fn part_1(&self, cracker: &SafeCracker) -> Result<Answer> {
         tracing::info!("Day 1: Part 1");
-        let raw_moves = load_from_file("inputs/day_1/part1.txt")?;
-        let cracker = SafeCracker::from_raw_inputs(50, raw_moves, 100)?;
-        let count = cracker.count_zeros();
-        tracing::info!("The password is {}", count);
-        Ok(())
+        Ok(cracker.count_zeros().into())
     }
 
-    fn part_2(&self) -> Result<()> {
-        println!("Day 1: Part 2");
-        let raw_moves = load_from_file("inputs/day_1/part1.txt")?;
-        let cracker = SafeCracker::from_raw_inputs(50, raw_moves, 100)?;
-        let count = cracker.count_zero_incl_passes();
-        tracing::info!("The password is {}", count);
-        Ok(())
+    fn part_2(&self, cracker: &SafeCracker) -> Result<Answer> {
+        tracing::info!("Day 1: Part 2");
+        Ok(cracker.count_zero_incl_passes().into())
     }
 }
 
@@ -50,7 +64,7 @@ impl FromStr for Direction {
     }
 }
 
-struct Move {
+pub struct Move {
     direction: Direction,
     steps: i32,
 }
@@ -66,7 +80,28 @@ impl FromStr for Move {
     }
 }
 
-struct SafeCracker {
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", direction, self.steps)
+    }
+}
+
+/// What a single move did in `run_with_passes`.
+pub struct Turn<'a> {
+    pub rotation: &'a Move,
+    pub from: i32,
+    pub to: i32,
+    /// 1 if a pass was added for going below 0
+    pub bias: i32,
+    /// The passes of 0 counted for this move
+    pub passes: i32,
+}
+
+pub struct SafeCracker {
     start_position: i32,
     moves: Vec<Move>,
     dial_size: i32,
@@ -90,11 +125,11 @@ impl SafeCracker {
             .iter()
             .map(|s| s.parse::<Move>())
             .collect::<Result<Vec<Move>, ParseMoveError>>()?;
-        Ok(Self {
-            start_position,
-            moves: parsed,
-            dial_size,
-        })
+        Ok(Self::new(start_position, parsed, dial_size))
+    }
+
+    pub fn dial_size(&self) -> i32 {
+        self.dial_size
     }
 
     pub fn run(&self) -> Vec<i32> {
@@ -113,7 +148,7 @@ impl SafeCracker {
         positions
     }
 
-    pub fn run_with_passes(&self) -> (i32, Vec<i32>) {
+    pub fn run_with_passes(&self, mut on_turn: impl FnMut(Turn)) -> (i32, Vec<i32>) {
         let mut positions = vec![self.start_position];
         let mut current_position = self.start_position;
         let mut passes = 0;
@@ -136,6 +171,13 @@ impl SafeCracker {
             }
             passes += new_passes;
             positions.push(current_position);
+            on_turn(Turn {
+                rotation: m,
+                from: initial,
+                to: current_position,
+                bias,
+                passes: new_passes,
+            });
         }
         (passes, positions)
     }
@@ -145,7 +187,7 @@ impl SafeCracker {
     }
 
     pub fn count_zero_incl_passes(&self) -> i32 {
-        let (passes, positions) = self.run_with_passes();
+        let (passes, positions) = self.run_with_passes(|_| {});
         positions.iter().filter(|&&p| p == 0).count() as i32 + passes
     }
 }
@@ -154,9 +196,11 @@ impl SafeCracker {
 mod tests {
     use super::*;
 
+    crate::utils::examples::example_tests!(2025, 1, DayOne);
+
     #[test]
     fn test_pt1() {
-        let moves = vec!["R50", "L50", "R100", "L1"]
+        let moves = ["R50", "L50", "R100", "L1"]
             .iter()
             .map(|s| s.to_string())
             .collect();
@@ -167,7 +211,7 @@ mod tests {
 
     #[test]
     fn test_pt2() {
-        let moves = vec!["R50", "L50", "L500", "L1"]
+        let moves = ["R50", "L50", "L500", "L1"]
             .iter()
             .map(|s| s.to_string())
             .collect();
@@ -175,4 +219,11 @@ mod tests {
         let result = cracker.count_zero_incl_passes();
         assert_eq!(result, 6);
     }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = DayOne.parse("R5\nL10 X7").err().unwrap();
+        let diagnostic = error.downcast::<Diagnostic>().unwrap();
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 5));
+    }
 }
@@ -0,0 +1,114 @@
+use crate::days::y2025::day_1::run::{DayOne, SafeCracker};
+use crate::utils::models::Part;
+use crate::utils::visualize::{Frame, RegisteredVisualization, Visualize};
+use anyhow::Result;
+
+inventory::submit! {
+    RegisteredVisualization::new(2025, 1, &DayOne)
+}
+
+/// Draws the dial as a square ring of positions, clockwise with 0 at the top, with `@` where
+/// it's pointing and `o` at 0. Dials that don't fit a square are drawn as a single row.
+fn dial(size: usize, position: usize) -> Vec<Vec<char>> {
+    let mark = |p: usize| match p {
+        _ if p == position => '@',
+        0 => 'o',
+        _ => '.',
+    };
+    if !size.is_multiple_of(4) || size < 8 {
+        return vec![(0..size).map(mark).collect()];
+    }
+    let edge = size / 4;
+    let mut rows = vec![vec![' '; edge + 1]; edge + 1];
+    for p in 0..size {
+        // Walk the ring from the top left corner, starting from the middle of the top edge
+        let k = (p + edge / 2) % size;
+        let (x, y) = match k / edge {
+            0 => (k, 0),
+            1 => (edge, k - edge),
+            2 => (3 * edge - k, edge),
+            _ => (0, 4 * edge - k),
+        };
+        rows[y][x] = mark(p);
+    }
+    rows
+}
+
+/// One frame per move. Part 2 follows `run_with_passes`, so its counters show how each
+/// move's passes of 0 were worked out.
+impl Visualize for DayOne {
+    fn frames(&self, part: Part, cracker: &SafeCracker) -> Result<Vec<Frame>> {
+        let size = cracker.dial_size() as usize;
+        let positions = cracker.run();
+        let start = Frame::new("Start", dial(size, positions[0] as usize))
+            .with_counter("position", positions[0])
+            .with_counter("zeros", 0);
+        let mut frames = vec![start];
+        let mut zeros = 0;
+        match part {
+            Part::Part1 => {
+                for (i, &position) in positions.iter().enumerate().skip(1) {
+                    zeros += (position == 0) as i32;
+                    let frame = Frame::new(format!("Move {}", i), dial(size, position as usize))
+                        .with_counter("position", position)
+                        .with_counter("zeros", zeros);
+                    frames.push(frame);
+                }
+            }
+            Part::Part2 => {
+                let mut passes = 0;
+                cracker.run_with_passes(|turn| {
+                    zeros += (turn.to == 0) as i32;
+                    passes += turn.passes;
+                    let caption = format!("Move {}: {}", frames.len(), turn.rotation);
+                    let frame = Frame::new(caption, dial(size, turn.to as usize))
+                        .with_counter("position", format!("{} -> {}", turn.from, turn.to))
+                        .with_counter("bias", turn.bias)
+                        .with_counter("passes this move", turn.passes)
+                        .with_counter("passes", passes)
+                        .with_counter("zeros", zeros)
+                        .with_counter("password", zeros + passes);
+                    frames.push(frame);
+                });
+            }
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::advent_day::AdventDay;
+
+    #[test]
+    fn test_dial() {
+        let rows = dial(8, 2);
+        let lines: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+        assert_eq!(lines, [".o.", ". @", "..."]);
+        assert_eq!(dial(6, 2), [vec!['o', '.', '@', '.', '.', '.']]);
+    }
+
+    #[test]
+    fn test_frames() {
+        let cracker = DayOne.parse("L68 L30 R48").unwrap();
+        let frames = Visualize::frames(&DayOne, Part::Part1, &cracker).unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[3].caption, "Move 3");
+        assert_eq!(
+            frames[3].counters[1],
+            ("zeros".to_string(), "1".to_string())
+        );
+        assert_eq!((frames[0].width(), frames[0].height()), (26, 26));
+        assert_eq!(frames[3].rows[0][12], '@');
+
+        let frames = Visualize::frames(&DayOne, Part::Part2, &cracker).unwrap();
+        assert_eq!(
+            frames[1].to_string().lines().take(2).collect::<Vec<_>>(),
+            [
+                "Move 1: L68",
+                "position: 50 -> 82, bias: 1, passes this move: 1, passes: 1, zeros: 0, password: 1"
+            ]
+        );
+    }
+}
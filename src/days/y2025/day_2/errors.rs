@@ -6,6 +6,7 @@ use thiserror::Error;
 pub struct ParseRangeError;
 
 #[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum ParseProductError {
     /// Error when the direction fails to parse
     #[error("Product range parsing failed: {0}")]
@@ -1,6 +1,6 @@
-use crate::days::day_2::errors::ParseProductError;
-use crate::days::day_2::errors::ParseProductError::InvalidFormat;
-use crate::days::day_2::utils::into_matching_snippets;
+use crate::days::y2025::day_2::errors::ParseProductError;
+use crate::days::y2025::day_2::errors::ParseProductError::InvalidFormat;
+use crate::days::y2025::day_2::utils::into_matching_snippets;
 use std::str::FromStr;
 
 pub struct ProductRange {
@@ -18,7 +18,7 @@ impl FromStr for ProductRange {
         }
         let min = value[0].parse::<u64>()?;
         let max = value[1].parse::<u64>()?;
-        Ok(Self { min, max })
+        Ok(Self::new(min, max))
     }
 }
 
@@ -43,10 +43,12 @@ impl ProductRange {
             .collect()
     }
 
+    /// The candidate if it's some snippet of its digits repeated. The snippet repeats at least
+    /// twice, so is at most half as long as the candidate.
     fn check_one(&self, candidate: u64) -> Option<u64> {
-        (1..(candidate / 2) as u32)
-            .filter_map(move |f| into_matching_snippets(candidate, f as usize).map(|_| candidate))
-            .next()
+        let digits = candidate.to_string().len();
+        (1..=digits / 2)
+            .find_map(|len| into_matching_snippets(candidate, len))
             .map(|_| candidate)
     }
 }
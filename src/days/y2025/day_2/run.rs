@@ -0,0 +1,54 @@
+use crate::days::y2025::day_2::product_range::ProductRange;
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
+use anyhow::Result;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub struct DayTwo;
+
+inventory::submit! {
+    RegisteredDay::new(2025, 2, "Gift Shop", &DayTwo)
+}
+
+pub fn get_products(input: &str) -> Result<Vec<ProductRange>> {
+    Ok(input
+        .split(',')
+        .map(str::trim)
+        .map(|raw| ProductRange::from_str(raw).map_err(|e| Diagnostic::new(input, raw, e)))
+        .collect::<Result<Vec<ProductRange>, Diagnostic>>()?)
+}
+
+impl AdventDay for DayTwo {
+    type Input = Vec<ProductRange>;
+
+    fn parse(&self, input: &str) -> Result<Vec<ProductRange>> {
+        get_products(input)
+    }
+
+    fn part_1(&self, products: &Vec<ProductRange>) -> Result<Answer> {
+        tracing::info!("Day 2: Part 1");
+        let sum: u64 = products.iter().flat_map(|r| r.get_repeating_twice()).sum();
+        Ok(sum.into())
+    }
+
+    fn part_2(&self, products: &Vec<ProductRange>) -> Result<Answer> {
+        tracing::info!("Day 2: Part 2");
+        let sum: u64 = products
+            .iter()
+            .flat_map(|r| r.get_repeating())
+            .collect::<HashSet<_>>()
+            .iter()
+            .sum();
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::utils::examples::example_tests!(2025, 2, DayTwo);
+}
@@ -1,38 +1,61 @@
-use crate::days::day_3::errors::ParseBankError;
+use crate::days::y2025::day_3::errors::ParseBankError;
 use crate::utils::advent_day::AdventDay;
-use crate::utils::load::load_from_file;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
 use anyhow::{Error, Result};
 use std::cmp::{max, Ordering};
 
 pub struct DayThree;
 
+inventory::submit! {
+    RegisteredDay::new(2025, 3, "Lobby", &DayThree)
+}
+
 impl AdventDay for DayThree {
-    fn part_1(&self) -> Result<()> {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(input
+            .split_whitespace()
+            .map(|bank| check_bank(input, bank).map(|_| bank.to_string()))
+            .collect::<Result<_, Diagnostic>>()?)
+    }
+
+    fn part_1(&self, banks: &Vec<String>) -> Result<Answer> {
         tracing::info!("Day 3: Part 1");
-        let banks = load_from_file("inputs/day_3/part1.txt")?;
         let values: Result<Vec<u64>> = banks
             .iter()
             .map(|bank| maximise_joltage_n_times(bank, 2))
             .collect();
         let sum: u64 = values?.iter().sum();
-
-        tracing::info!("The sum of max joltages is {}", sum);
-        Ok(())
+        Ok(sum.into())
     }
 
-    fn part_2(&self) -> Result<()> {
+    fn part_2(&self, banks: &Vec<String>) -> Result<Answer> {
         tracing::info!("Day 3: Part 2");
-
-        let banks = load_from_file("inputs/day_3/part1.txt")?;
         let values: Result<Vec<u64>> = banks
             .iter()
             .map(|bank| maximise_joltage_n_times(bank, 12))
             .collect();
         let sum: u64 = values?.iter().sum();
+        Ok(sum.into())
+    }
+}
 
-        tracing::info!("The sum of max joltages is {}", sum);
-        Ok(())
+/// Points at the first character of `bank` that isn't a digit, or the whole bank if it's too short.
+fn check_bank(input: &str, bank: &str) -> Result<(), Diagnostic> {
+    if let Some((idx, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(Diagnostic::new(
+            input,
+            &bank[idx..idx + c.len_utf8()],
+            ParseBankError,
+        ));
     }
+    if bank.len() < 2 {
+        return Err(Diagnostic::new(input, bank, ParseBankError));
+    }
+    Ok(())
 }
 
 fn get_max_char(val: &str) -> Result<(u32, usize)> {
@@ -67,6 +90,8 @@ pub fn maximise_joltage_n_times(bank: &str, n: usize) -> Result<u64> {
 mod tests {
     use super::*;
 
+    crate::utils::examples::example_tests!(2025, 3, DayThree);
+
     #[test]
     fn test_maximise_joltage() {
         let joltage = maximise_joltage_n_times("811111111111119", 2).unwrap();
@@ -1,30 +1,33 @@
 use crate::utils::advent_day::AdventDay;
-use crate::utils::grid::Grid;
-use crate::utils::load::raw_load_from_file;
+use crate::utils::answer::Answer;
+use crate::utils::grid::{check_grid, Grid};
+use crate::utils::load::raw_load_from_str;
+use crate::utils::registry::RegisteredDay;
 use anyhow::Result;
 use std::str::FromStr;
 
 pub struct DayFour;
 
+inventory::submit! {
+    RegisteredDay::new(2025, 4, "Printing Department", &DayFour)
+}
+
 impl AdventDay for DayFour {
-    fn part_1(&self) -> Result<()> {
-        tracing::info!("Day 4: Part 1");
-        let input = raw_load_from_file("inputs/day_4/part1.txt")?;
-        let grid = Grid::from_str(&input)?;
-        let count = find_paper(grid)?;
+    type Input = Grid<char>;
 
-        tracing::info!("Found {} valid paper", count);
-        Ok(())
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        check_grid(input, &['.', '@'])?;
+        Ok(Grid::from_str(&raw_load_from_str(input))?)
     }
 
-    fn part_2(&self) -> Result<()> {
-        tracing::info!("Day 4: Part 2");
-        let input = raw_load_from_file("inputs/day_4/part1.txt")?;
-        let grid = Grid::from_str(&input)?;
-        let count = find_and_remove_paper(grid)?;
+    fn part_1(&self, grid: &Grid<char>) -> Result<Answer> {
+        tracing::info!("Day 4: Part 1");
+        Ok(find_paper(grid)?.into())
+    }
 
-        tracing::info!("Found {} valid paper", count);
-        Ok(())
+    fn part_2(&self, grid: &Grid<char>) -> Result<Answer> {
+        tracing::info!("Day 4: Part 2");
+        Ok(find_and_remove_paper(grid.clone(), |_, _| {})?.into())
     }
 }
 
@@ -56,23 +59,33 @@ fn count_of_char(chars: Vec<char>, c: char) -> usize {
     chars.iter().filter(|&&x| x == c).count()
 }
 
-fn find_paper(plan: Grid<char>) -> Result<i32> {
-    let mut valid_count = 0;
+fn find_paper(plan: &Grid<char>) -> Result<i32> {
+    Ok(accessible_paper(plan).len() as i32)
+}
+
+/// The (x, y) of every roll of paper with fewer than four rolls around it.
+pub fn accessible_paper(plan: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut accessible = vec![];
 
     for (y_idx, row) in plan.iter().enumerate() {
         for (x_idx, symbol) in row.iter().enumerate() {
             if symbol == &'@' {
                 let neighbours = count_of_char(plan.neighbours(x_idx, y_idx), '@');
                 if neighbours < 4 {
-                    valid_count += 1
+                    accessible.push((x_idx, y_idx))
                 }
             }
         }
     }
-    Ok(valid_count)
+    accessible
 }
 
-fn find_and_remove_paper(mut plan: Grid<char>) -> Result<i32> {
+/// Removes paper in waves until none can be removed, calling `on_wave` with the plan and the
+/// number removed after each wave.
+pub fn find_and_remove_paper(
+    mut plan: Grid<char>,
+    mut on_wave: impl FnMut(&Grid<char>, i32),
+) -> Result<i32> {
     let mut prev_count: i32 = 0;
     let mut valid_count = 0;
     let mut flag = true;
@@ -91,6 +104,7 @@ fn find_and_remove_paper(mut plan: Grid<char>) -> Result<i32> {
                 }
             }
         }
+        on_wave(&plan, valid_count - prev_count);
     }
     Ok(valid_count)
 }
@@ -99,37 +113,21 @@ fn find_and_remove_paper(mut plan: Grid<char>) -> Result<i32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_find_paper() {
-        let test_input = r"..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        let grid = Grid::from_str(test_input).unwrap();
-        let count = find_paper(grid).unwrap();
-        assert_eq!(count, 13);
-    }
+    crate::utils::examples::example_tests!(2025, 4, DayFour);
 
     #[test]
-    fn test_find_and_remove_paper() {
-        let test_input = r"..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        let grid = Grid::from_str(test_input).unwrap();
-        let count = find_and_remove_paper(grid).unwrap();
-        assert_eq!(count, 43);
+    fn test_parse_errors() {
+        let diagnostic = |input| {
+            DayFour
+                .parse(input)
+                .err()
+                .unwrap()
+                .downcast::<crate::utils::diagnostic::Diagnostic>()
+                .unwrap()
+        };
+        let unexpected = diagnostic("..@\n.#.\n@@.");
+        assert_eq!((unexpected.line, unexpected.column), (2, 2));
+        let ragged = diagnostic("..@\n.@\n@@.");
+        assert_eq!((ragged.line, ragged.column, ragged.width), (2, 1, 2));
     }
 }
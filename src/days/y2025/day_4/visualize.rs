@@ -0,0 +1,66 @@
+use crate::days::y2025::day_4::run::{accessible_paper, find_and_remove_paper, DayFour};
+use crate::utils::grid::Grid;
+use crate::utils::models::Part;
+use crate::utils::visualize::{Frame, RegisteredVisualization, Visualize};
+use anyhow::Result;
+
+inventory::submit! {
+    RegisteredVisualization::new(2025, 4, &DayFour)
+}
+
+/// Part 1 marks the rolls the forklifts can reach, and part 2 shows each wave of removals.
+impl Visualize for DayFour {
+    fn frames(&self, part: Part, grid: &Grid<char>) -> Result<Vec<Frame>> {
+        let mut frames = vec![Frame::new("Start", grid.values.clone())];
+        match part {
+            Part::Part1 => {
+                let accessible = accessible_paper(grid);
+                let mut marked = grid.clone();
+                for &(x, y) in &accessible {
+                    marked.remove(x, y);
+                }
+                let frame = Frame::new("Reachable rolls", marked.values)
+                    .with_counter("reachable", accessible.len());
+                frames.push(frame);
+            }
+            Part::Part2 => {
+                let mut total = 0;
+                find_and_remove_paper(grid.clone(), |plan, removed| {
+                    total += removed;
+                    let frame = Frame::new(format!("Wave {}", frames.len()), plan.values.clone())
+                        .with_counter("removed this wave", removed)
+                        .with_counter("removed", total);
+                    frames.push(frame);
+                })?;
+            }
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::advent_day::AdventDay;
+    use crate::utils::examples::load_examples;
+    use crate::utils::models::{CalendarDay, Day, Year};
+
+    #[test]
+    fn test_frames() {
+        let example = &load_examples(CalendarDay::new(Year::new(2025), Day::new(4)))[0];
+        let grid = DayFour.parse(&example.input).unwrap();
+        let counter = |frame: &Frame, i: usize| frame.counters[i].1.clone();
+        let reached = Visualize::frames(&DayFour, Part::Part1, &grid).unwrap();
+        assert_eq!(counter(&reached[1], 0), "13");
+
+        let waves = Visualize::frames(&DayFour, Part::Part2, &grid).unwrap();
+        assert_eq!(waves[1].caption, "Wave 1");
+        assert_eq!(counter(&waves[1], 0), "30");
+        // The last wave removes nothing, which is how the removals are known to be finished
+        let last = waves.last().unwrap();
+        assert_eq!(
+            (counter(last, 0), counter(last, 1)),
+            ("0".into(), "43".into())
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
+use anyhow::Result;
+
+pub struct DayFive;
+
+inventory::submit! {
+    RegisteredDay::new(2025, 5, "Cafeteria", &DayFive)
+}
+
+impl AdventDay for DayFive {
+    type Input = Ingredients;
+
+    fn parse(&self, input: &str) -> Result<Ingredients> {
+        let (raw_fresh, raw_available) = split_ingredients(input)?;
+        Ok(Ingredients {
+            fresh: RangeSet::parse(input, raw_fresh)?,
+            available: parse_available(input, raw_available)?,
+        })
+    }
+
+    fn part_1(&self, ingredients: &Ingredients) -> Result<Answer> {
+        tracing::info!("Day 5: Part 1");
+        Ok(count_fresh(ingredients).into())
+    }
+
+    fn part_2(&self, ingredients: &Ingredients) -> Result<Answer> {
+        tracing::info!("Day 5: Part 2");
+        Ok(count_all_possible_fresh(ingredients).into())
+    }
+}
+
+pub struct Ingredients {
+    fresh: RangeSet,
+    available: Vec<i64>,
+}
+
+struct RangeSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl RangeSet {
+    /// Parses the `raw_fresh` section of `input`, one `min-max` range per line.
+    fn parse(input: &str, raw_fresh: &str) -> Result<Self, Diagnostic> {
+        let parse_bound = |bound: &str| {
+            bound
+                .parse::<i64>()
+                .map_err(|e| Diagnostic::new(input, bound, format!("Invalid range bound: {}", e)))
+        };
+        Ok(Self {
+            ranges: raw_fresh
+                .lines()
+                .map(|line| {
+                    let (min_str, max_str) = line.split_once("-").ok_or_else(|| {
+                        Diagnostic::new(input, line, "Missing delimiter: expected 'min-max'")
+                    })?;
+                    Ok((parse_bound(min_str)?, parse_bound(max_str)?))
+                })
+                .collect::<Result<_, Diagnostic>>()?,
+        })
+    }
+
+    fn condense(&self) -> Vec<(i64, i64)> {
+        let mut ranges = self.ranges.clone();
+        ranges.sort_by_key(|(min, _)| *min);
+        ranges
+            .into_iter()
+            .fold(Vec::new(), |mut acc, (c_min, c_max)| {
+                if let Some(e) = acc.last_mut() {
+                    let (_, e_max) = *e;
+                    if c_min <= e_max {
+                        e.1 = e_max.max(c_max);
+                    } else {
+                        acc.push((c_min, c_max));
+                    }
+                } else {
+                    acc.push((c_min, c_max));
+                }
+                acc
+            })
+    }
+}
+
+fn split_ingredients(input: &str) -> Result<(&str, &str), Diagnostic> {
+    input.trim().split_once("\n\n").ok_or_else(|| {
+        Diagnostic::at_end(
+            input.trim_end(),
+            "Missing ingredient separator: expected a blank line after the fresh ranges",
+        )
+    })
+}
+
+fn parse_available(input: &str, raw_available: &str) -> Result<Vec<i64>, Diagnostic> {
+    raw_available
+        .lines()
+        .map(|line| {
+            line.parse::<i64>()
+                .map_err(|e| Diagnostic::new(input, line, format!("Invalid ingredient: {}", e)))
+        })
+        .collect()
+}
+
+fn count_overlapping(ranges: Vec<(i64, i64)>, points: &[i64]) -> usize {
+    points
+        .iter()
+        .filter_map(|ingredient| {
+            ranges
+                .iter()
+                .find(|range| ingredient >= &range.0 && ingredient <= &range.1)
+        })
+        .count()
+}
+
+fn count_fresh(ingredients: &Ingredients) -> usize {
+    count_overlapping(ingredients.fresh.condense(), &ingredients.available)
+}
+
+fn count_all_possible_fresh(ingredients: &Ingredients) -> i64 {
+    tracing::info!("Counting ingredients");
+    ingredients
+        .fresh
+        .condense()
+        .into_iter()
+        .fold(0, |acc, (min, max)| acc + max - min + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::utils::examples::example_tests!(2025, 5, DayFive);
+
+    #[test]
+    fn test_parse_errors() {
+        let diagnostic = |input| {
+            DayFive
+                .parse(input)
+                .err()
+                .unwrap()
+                .downcast::<Diagnostic>()
+                .unwrap()
+        };
+        let ingredient = diagnostic("3-5\n10-14\n\n1\nfive\n8");
+        assert_eq!(
+            (ingredient.line, ingredient.line_text.as_str()),
+            (5, "five")
+        );
+        let bound = diagnostic("3-5\n10-x\n\n1");
+        assert_eq!((bound.line, bound.column), (2, 4));
+        assert_eq!(diagnostic("3-5\n1\n").line, 2);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
+use anyhow::Result;
+use itertools::Itertools;
+use std::ops::Range;
+
+pub struct DaySix;
+
+inventory::submit! {
+    RegisteredDay::new(2025, 6, "Trash Compactor", &DaySix)
+}
+
+impl AdventDay for DaySix {
+    type Input = Worksheet;
+
+    fn parse(&self, input: &str) -> Result<Worksheet> {
+        Ok(parse_worksheet(input)?)
+    }
+
+    fn part_1(&self, worksheet: &Worksheet) -> Result<Answer> {
+        tracing::info!("Day 6: Part 1");
+        Ok(do_math_homework(worksheet)?.into())
+    }
+
+    fn part_2(&self, worksheet: &Worksheet) -> Result<Answer> {
+        tracing::info!("Day 6: Part 2");
+        Ok(do_math_homework_pt2(worksheet)?.into())
+    }
+}
+
+const OPERATIONS: [char; 4] = ['+', '-', '*', '/'];
+
+/// The sheet as a grid of characters, so the parts can read its numbers across rows or down
+/// columns.
+#[derive(Debug)]
+pub struct Worksheet {
+    /// The rows of digits and spaces, padded to the same width
+    digits: Vec<Vec<char>>,
+    problems: Vec<Problem>,
+}
+
+/// A problem on the sheet: the columns its numbers are written in, and its operation.
+#[derive(Debug)]
+struct Problem {
+    columns: Range<usize>,
+    operation: char,
+}
+
+/// Reads the sheet as rows of numbers followed by a row of operations, with the problems
+/// separated by columns of spaces.
+fn parse_worksheet(input: &str) -> Result<Worksheet, Diagnostic> {
+    let lines = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect_vec();
+    let Some((operations, numbers)) = lines
+        .split_last()
+        .filter(|(_, numbers)| !numbers.is_empty())
+    else {
+        return Err(Diagnostic::at_end(
+            input.trim_end(),
+            "Expected rows of numbers followed by a row of operations",
+        ));
+    };
+    let unexpected = |line: &'_ str, allowed: fn(char) -> bool, expected: &str| {
+        line.char_indices()
+            .find(|(_, c)| !allowed(*c))
+            .map(|(idx, c)| {
+                Diagnostic::new(
+                    input,
+                    &line[idx..idx + c.len_utf8()],
+                    format!("Unexpected '{}', expected {}", c, expected),
+                )
+            })
+    };
+    for line in numbers {
+        if let Some(diagnostic) =
+            unexpected(line, |c| c.is_ascii_digit() || c == ' ', "a digit or space")
+        {
+            return Err(diagnostic);
+        }
+    }
+    if let Some(diagnostic) = unexpected(
+        operations,
+        |c| OPERATIONS.contains(&c) || c == ' ',
+        "one of + - * / or a space",
+    ) {
+        return Err(diagnostic);
+    }
+
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let pad = |line: &str| {
+        let mut row = line.chars().collect_vec();
+        row.resize(width, ' ');
+        row
+    };
+    let digits = numbers.iter().map(|line| pad(line)).collect_vec();
+    let operations = pad(operations);
+    let blank = |x: usize| operations[x] == ' ' && digits.iter().all(|row| row[x] == ' ');
+
+    let mut problems = vec![];
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !blank(x) {
+            x += 1;
+        }
+        let columns = start..x;
+        let found = columns
+            .clone()
+            .filter(|&x| operations[x] != ' ')
+            .collect_vec();
+        let [at] = found[..] else {
+            let line = lines[lines.len() - 1];
+            let span = &line[start.min(line.len())..x.min(line.len())];
+            return Err(Diagnostic::new(
+                input,
+                span,
+                format!("Expected one operation per problem, found {}", found.len()),
+            ));
+        };
+        for (row, line) in digits.iter().zip(numbers) {
+            if row[columns.clone()].iter().all(|&c| c == ' ') {
+                return Err(Diagnostic::new(
+                    input,
+                    &line[start.min(line.len())..x.min(line.len())],
+                    "Expected a number for this problem",
+                ));
+            }
+        }
+        problems.push(Problem {
+            columns,
+            operation: operations[at],
+        });
+    }
+    Ok(Worksheet { digits, problems })
+}
+
+struct MathColumn {
+    numbers: Vec<i64>,
+    operation: char,
+}
+
+impl MathColumn {
+    fn new(numbers: Vec<i64>, operation: char) -> Self {
+        assert!(!numbers.is_empty());
+        assert!(OPERATIONS.contains(&operation));
+        Self { numbers, operation }
+    }
+}
+
+impl MathColumn {
+    fn calculate(&self) -> Option<i64> {
+        self.numbers
+            .clone()
+            .into_iter()
+            .reduce(operation_from_char(self.operation))
+    }
+}
+
+fn operation_from_char(char: char) -> impl Fn(i64, i64) -> i64 {
+    if char == '+' {
+        |a, b| a + b
+    } else if char == '-' {
+        |a, b| a - b
+    } else if char == '*' {
+        |a, b| a * b
+    } else {
+        |a, b| a / b
+    }
+}
+
+/// Parses the digits in `cells`, ignoring spaces, or `None` if there aren't any.
+fn read_number(cells: impl Iterator<Item = char>) -> Option<Result<i64>> {
+    let number = cells.filter(|c| *c != ' ').collect::<String>();
+    if number.is_empty() {
+        None
+    } else {
+        Some(number.parse::<i64>().map_err(Into::into))
+    }
+}
+
+/// Sums the problems, with their numbers read across the rows.
+fn do_math_homework(worksheet: &Worksheet) -> Result<i64> {
+    let columns = worksheet
+        .problems
+        .iter()
+        .map(|problem| {
+            let numbers = worksheet
+                .digits
+                .iter()
+                .filter_map(|row| read_number(row[problem.columns.clone()].iter().copied()))
+                .collect::<Result<_>>()?;
+            Ok(MathColumn::new(numbers, problem.operation))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(columns
+        .iter()
+        .fold(0, |acc, col| acc + col.calculate().unwrap_or(0)))
+}
+
+/// Sums the problems, with their numbers read down the columns.
+fn do_math_homework_pt2(worksheet: &Worksheet) -> Result<i64> {
+    let columns = worksheet
+        .problems
+        .iter()
+        .map(|problem| {
+            let numbers = problem
+                .columns
+                .clone()
+                .filter_map(|x| read_number(worksheet.digits.iter().map(|row| row[x])))
+                .collect::<Result<_>>()?;
+            Ok(MathColumn::new(numbers, problem.operation))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(columns
+        .iter()
+        .fold(0, |acc, col| acc + col.calculate().unwrap_or(0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_worksheet() {
+        let worksheet = parse_worksheet("12  3\n 4 56\n*   +").unwrap();
+        let columns = worksheet.problems.iter().map(|p| p.columns.clone());
+        assert_eq!(columns.collect_vec(), [0..2, 3..5]);
+        assert_eq!(do_math_homework(&worksheet).unwrap(), 12 * 4 + 3 + 56);
+        // Read down the columns, the numbers are 1 * 24 and 5 + 36
+        assert_eq!(do_math_homework_pt2(&worksheet).unwrap(), 65);
+
+        let missing = parse_worksheet("12  3\n 4 56\n*    ").unwrap_err();
+        assert_eq!((missing.line, missing.column), (3, 4));
+    }
+
+    crate::utils::examples::example_tests!(2025, 6, DaySix);
+}
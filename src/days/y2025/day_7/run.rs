@@ -1,34 +1,47 @@
 use crate::utils::advent_day::AdventDay;
-use crate::utils::load::load_from_file;
+use crate::utils::answer::Answer;
+use crate::utils::grid::check_grid;
+use crate::utils::load::load_from_str;
+use crate::utils::registry::RegisteredDay;
 use crate::utils::sets::inplace_intersection;
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 
 pub struct DaySeven;
 
+inventory::submit! {
+    RegisteredDay::new(2025, 7, "Laboratories", &DaySeven)
+}
+
 impl AdventDay for DaySeven {
-    fn part_1(&self) -> Result<()> {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        check_grid(input, &['.', '^', 'S'])?;
+        Ok(load_from_str(input))
+    }
+
+    fn part_1(&self, manifold: &Vec<String>) -> Result<Answer> {
         tracing::info!("Day 7: Part 1");
-        let input = load_from_file("inputs/day_7/part1.txt")?;
-        let count = count_beam_splits(input)?;
-        tracing::info!("Beam is split {} times", count);
-        Ok(())
+        Ok(count_beam_splits(manifold, |_, _, _| {})?.into())
     }
 
-    fn part_2(&self) -> Result<()> {
+    fn part_2(&self, manifold: &Vec<String>) -> Result<Answer> {
         tracing::info!("Day 7: Part 2");
-        let input = load_from_file("inputs/day_7/part1.txt")?;
-        let count = count_total_timelines(input)?;
-        tracing::info!("Found {} total paths", count);
-        Ok(())
+        Ok(count_total_timelines(manifold, |_, _| {})?.into())
     }
 }
 
-fn count_beam_splits(input: Vec<String>) -> Result<i32> {
+/// Counts the times a beam is split, calling `on_row` with each row's index, the columns of
+/// the beams in it, and the splits so far.
+pub fn count_beam_splits(
+    input: &[String],
+    mut on_row: impl FnMut(usize, &[usize], usize),
+) -> Result<i32> {
     let mut count = 0;
     let mut splitter_indexes = vec![];
     let mut beam_indexes: Vec<usize> = vec![];
-    for line in input.iter() {
+    for (row, line) in input.iter().enumerate() {
         for (i, char) in line.chars().enumerate() {
             if char == '^' {
                 splitter_indexes.push(i);
@@ -42,6 +55,7 @@ fn count_beam_splits(input: Vec<String>) -> Result<i32> {
             &mut HashSet::from_iter(splitter_indexes.iter().copied()),
         );
         count += overlap.len();
+        on_row(row, &beam_indexes, count);
         beam_indexes = get_new_beams(beam_indexes, overlap.into_iter().collect(), line.len());
         splitter_indexes.clear();
     }
@@ -63,7 +77,12 @@ pub fn get_new_beams(mut current: Vec<usize>, overlap: Vec<usize>, max: usize) -
     current
 }
 
-fn count_total_timelines(input: Vec<String>) -> Result<u64> {
+/// Counts the timelines a particle ends up in, calling `on_row` with each row's index and
+/// the number of timelines at each column in it.
+pub fn count_total_timelines(
+    input: &[String],
+    mut on_row: impl FnMut(usize, &HashMap<usize, u64>),
+) -> Result<u64> {
     let mut timeline_counts: HashMap<usize, u64> = HashMap::new();
 
     for (i, char) in input[0].chars().enumerate() {
@@ -71,8 +90,9 @@ fn count_total_timelines(input: Vec<String>) -> Result<u64> {
             timeline_counts.insert(i, 1);
         }
     }
+    on_row(0, &timeline_counts);
 
-    for line in input.iter().skip(1) {
+    for (row, line) in input.iter().enumerate().skip(1) {
         let mut next_counts: HashMap<usize, u64> = HashMap::new();
 
         for (&col_idx, &count) in &timeline_counts {
@@ -89,6 +109,7 @@ fn count_total_timelines(input: Vec<String>) -> Result<u64> {
             }
         }
         timeline_counts = next_counts;
+        on_row(row, &timeline_counts);
     }
 
     Ok(timeline_counts.values().sum())
@@ -98,51 +119,5 @@ fn count_total_timelines(input: Vec<String>) -> Result<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_count_beams_part1() {
-        let test_input = r".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-"
-        .to_string();
-        let total = count_beam_splits(test_input.lines().map(String::from).collect()).unwrap();
-        assert_eq!(total, 21);
-    }
-
-    #[test]
-    fn test_count_beams_pt2() {
-        let test_input = r".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-"
-        .to_string();
-        let total = count_total_timelines(test_input.lines().map(String::from).collect()).unwrap();
-        assert_eq!(total, 40);
-    }
+    crate::utils::examples::example_tests!(2025, 7, DaySeven);
 }
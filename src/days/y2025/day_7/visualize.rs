@@ -0,0 +1,74 @@
+use crate::days::y2025::day_7::run::{count_beam_splits, count_total_timelines, DaySeven};
+use crate::utils::models::Part;
+use crate::utils::visualize::{Frame, RegisteredVisualization, Visualize};
+use anyhow::Result;
+use itertools::Itertools;
+
+inventory::submit! {
+    RegisteredVisualization::new(2025, 7, &DaySeven)
+}
+
+/// Draws beams as they reach each row of the manifold, one row per frame.
+impl Visualize for DaySeven {
+    fn frames(&self, part: Part, manifold: &Vec<String>) -> Result<Vec<Frame>> {
+        let mut drawn: Vec<Vec<char>> =
+            manifold.iter().map(|line| line.chars().collect()).collect();
+        let mut frames = vec![];
+        let mut draw_row = |row: usize, beams: &[usize]| {
+            for &column in beams {
+                if let Some(cell @ '.') = drawn[row].get_mut(column) {
+                    *cell = '|';
+                }
+            }
+            // Neighbouring splitters can send beams into the same column
+            let columns = beams.iter().unique().count();
+            Frame::new(format!("Row {}", row), drawn.clone()).with_counter("beams", columns)
+        };
+        match part {
+            Part::Part1 => {
+                count_beam_splits(manifold, |row, beams, splits| {
+                    frames.push(draw_row(row, beams).with_counter("splits", splits));
+                })?;
+            }
+            Part::Part2 => {
+                count_total_timelines(manifold, |row, timelines| {
+                    let beams = timelines.keys().copied().collect_vec();
+                    let total: u64 = timelines.values().sum();
+                    frames.push(draw_row(row, &beams).with_counter("timelines", total));
+                })?;
+            }
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::advent_day::AdventDay;
+    use crate::utils::examples::load_examples;
+    use crate::utils::models::{CalendarDay, Day, Year};
+
+    #[test]
+    fn test_frames() {
+        let example = &load_examples(CalendarDay::new(Year::new(2025), Day::new(7)))[0];
+        let manifold = DaySeven.parse(&example.input).unwrap();
+        let frames = Visualize::frames(&DaySeven, Part::Part1, &manifold).unwrap();
+        assert_eq!(frames.len(), manifold.len());
+        // The splitters on row 4 both send a beam down the middle column
+        assert_eq!(
+            frames[6].counters[0],
+            ("beams".to_string(), "3".to_string())
+        );
+        let last = frames.last().unwrap();
+        assert_eq!(last.counters[1], ("splits".to_string(), "21".to_string()));
+        assert_eq!(last.rows[1], ".......|.......".chars().collect::<Vec<_>>());
+
+        let frames = Visualize::frames(&DaySeven, Part::Part2, &manifold).unwrap();
+        let last = frames.last().unwrap();
+        assert_eq!(
+            last.counters[1],
+            ("timelines".to_string(), "40".to_string())
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::coordinates::{Coordinate3D, Distance};
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::registry::RegisteredDay;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+pub struct DayEight;
+
+inventory::submit! {
+    RegisteredDay::new(2025, 8, "Playground", &DayEight)
+}
+
+impl AdventDay for DayEight {
+    type Input = Playground;
+
+    fn parse(&self, input: &str) -> Result<Playground> {
+        let coords = input
+            .split_whitespace()
+            .map(|raw| Coordinate3D::try_from(raw).map_err(|e| Diagnostic::new(input, raw, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sorted_pairs = get_sorted_pairs(&coords);
+        Ok(Playground {
+            coords,
+            sorted_pairs,
+        })
+    }
+
+    fn part_1(&self, playground: &Playground) -> Result<Answer> {
+        tracing::info!("Day 8: Part 1");
+        Ok(build_circuits(playground, 1000, |_, _, _| {})?.into())
+    }
+
+    fn part_2(&self, playground: &Playground) -> Result<Answer> {
+        tracing::info!("Day 8: Part 2");
+        Ok(build_circuits_pt2(playground, |_, _, _| {})?.into())
+    }
+}
+
+/// The junction boxes, and every pair of them from closest to furthest apart.
+pub struct Playground {
+    coords: Vec<Coordinate3D>,
+    sorted_pairs: Vec<(f64, Coordinate3D, Coordinate3D)>,
+}
+
+impl Playground {
+    pub fn coords(&self) -> &[Coordinate3D] {
+        &self.coords
+    }
+}
+
+/// A pair of junction boxes and the distance between them.
+pub type Pair = (f64, Coordinate3D, Coordinate3D);
+
+/// How connecting a pair of junction boxes changed the circuits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connection {
+    /// Neither box was in a circuit, so they start a new one
+    New,
+    /// One of the boxes was already in a circuit, which the other joins
+    Joined,
+    /// Both boxes were already in the same circuit, so nothing changed
+    Unchanged,
+    /// The boxes were in different circuits, which are now one
+    Merged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Circuit {
+    coords: HashSet<Coordinate3D>,
+}
+
+impl Circuit {
+    pub fn includes(&self, coord: Coordinate3D) -> bool {
+        self.coords.contains(&coord)
+    }
+
+    pub fn size(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = &Coordinate3D> {
+        self.coords.iter()
+    }
+
+    fn add(&mut self, coord: Coordinate3D) {
+        self.coords.insert(coord);
+    }
+
+    fn combine(&mut self, other: &Self) {
+        self.coords.extend(other.coords.iter().cloned());
+    }
+}
+
+fn get_sorted_pairs(coords: &[Coordinate3D]) -> Vec<(f64, Coordinate3D, Coordinate3D)> {
+    let mut sorted_pairs = coords
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| {
+            if a == b {
+                None
+            } else {
+                Some((a.distance_to(*b), *a, *b))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    sorted_pairs
+        .sort_by(|(d1, _, _), (d2, _, _)| d1.partial_cmp(d2).unwrap_or(std::cmp::Ordering::Equal));
+    sorted_pairs
+}
+
+/// Connects the `n` closest pairs, calling `on_connect` with the pair, what connecting it did
+/// and the circuits after each one.
+pub fn build_circuits(
+    playground: &Playground,
+    n: usize,
+    mut on_connect: impl FnMut(&Pair, Connection, &[Circuit]),
+) -> Result<i64> {
+    let mut circuits: Vec<Circuit> = vec![];
+    for pair @ (_, a, b) in playground.sorted_pairs.iter().take(n) {
+        let linkable_indices = circuits
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.includes(*a) || c.includes(*b))
+            .map(|(i, _)| i)
+            .collect_vec();
+
+        let connection = if linkable_indices.is_empty() {
+            circuits.push(Circuit {
+                coords: HashSet::from([*a, *b]),
+            });
+            Connection::New
+        } else if linkable_indices.len() == 1 {
+            let idx = linkable_indices[0];
+            if circuits[idx].includes(*a) && circuits[idx].includes(*b) {
+                Connection::Unchanged
+            } else {
+                circuits[idx].add(*a);
+                circuits[idx].add(*b);
+                Connection::Joined
+            }
+        } else if linkable_indices.len() == 2 {
+            let circuit2 = circuits[linkable_indices[1]].clone();
+            circuits[linkable_indices[0]].combine(&circuit2);
+            circuits.remove(linkable_indices[1]);
+            Connection::Merged
+        } else {
+            Err(anyhow!("Invalid state"))?
+        };
+        on_connect(pair, connection, &circuits);
+    }
+    circuits.sort_by_key(|c| Reverse(c.coords.len()));
+    Ok(circuits
+        .iter()
+        .take(3)
+        .map(|coord| coord.coords.len() as i64)
+        .product())
+}
+
+/// Connects pairs from the closest until every box is in one circuit, calling `on_connect`
+/// like [`build_circuits`].
+pub fn build_circuits_pt2(
+    playground: &Playground,
+    mut on_connect: impl FnMut(&Pair, Connection, &[Circuit]),
+) -> Result<i64> {
+    let mut circuits: Vec<Circuit> = playground
+        .coords
+        .iter()
+        .map(|c| Circuit {
+            coords: HashSet::from([*c]),
+        })
+        .collect();
+    for pair @ &(_, a, b) in &playground.sorted_pairs {
+        let linkable_indices = circuits
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.includes(a) || c.includes(b))
+            .map(|(i, _)| i)
+            .collect_vec();
+
+        if linkable_indices.is_empty() {
+            circuits.push(Circuit {
+                coords: HashSet::from([a, b]),
+            });
+            on_connect(pair, Connection::New, &circuits);
+        } else if linkable_indices.len() == 1 {
+            // Every box starts in a circuit of its own, so both are already in this one
+            on_connect(pair, Connection::Unchanged, &circuits);
+        } else if linkable_indices.len() == 2 {
+            let circuit2 = circuits[linkable_indices[1]].clone();
+            circuits[linkable_indices[0]].combine(&circuit2);
+            circuits.remove(linkable_indices[1]);
+            on_connect(pair, Connection::Merged, &circuits);
+            if circuits.len() == 1 {
+                return Ok(a.x * b.x);
+            }
+        } else {
+            Err(anyhow!("Invalid state"))?
+        }
+    }
+    Err(anyhow!("no solution found"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::examples::load_examples;
+    use crate::utils::models::{CalendarDay, Day, Year};
+
+    #[test]
+    fn test_build_circuits_part1() {
+        let example = &load_examples(CalendarDay::new(Year::new(2025), Day::new(8)))[0];
+        let playground = DayEight.parse(&example.input).unwrap();
+        let mut connections = vec![];
+        let total = build_circuits(&playground, 10, |_, connection, _| {
+            connections.push(connection)
+        })
+        .unwrap();
+        assert_eq!(total, 40);
+        // The puzzle's fourth closest pair is already connected through the first and third
+        assert_eq!(connections[3], Connection::Unchanged);
+    }
+
+    crate::utils::examples::example_tests!(2025, 8, DayEight, part_2);
+}
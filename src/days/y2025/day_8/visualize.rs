@@ -0,0 +1,135 @@
+use crate::days::y2025::day_8::run::{
+    build_circuits, build_circuits_pt2, Circuit, Connection, DayEight, Pair, Playground,
+};
+use crate::utils::coordinates::Coordinate3D;
+use crate::utils::models::Part;
+use crate::utils::visualize::{Frame, RegisteredVisualization, Visualize};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+inventory::submit! {
+    RegisteredVisualization::new(2025, 8, &DayEight)
+}
+
+/// The size of the grid the junction boxes are drawn on, looking down the z axis.
+const COLUMNS: i64 = 60;
+const ROWS: i64 = 30;
+
+/// Where each box is drawn. Boxes close together can share a cell.
+fn project(coords: &[Coordinate3D]) -> impl Fn(Coordinate3D) -> (usize, usize) {
+    let (min_x, max_x) = coords
+        .iter()
+        .map(|c| c.x)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+    let (min_y, max_y) = coords
+        .iter()
+        .map(|c| c.y)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+    move |c| {
+        let x = (c.x - min_x) * (COLUMNS - 1) / (max_x - min_x).max(1);
+        let y = (c.y - min_y) * (ROWS - 1) / (max_y - min_y).max(1);
+        (x as usize, y as usize)
+    }
+}
+
+/// One frame per pair connected, with boxes in a circuit drawn as `#`, the rest as `o` and the
+/// pair just connected as `@`. Part 1 connects the closest `pairs` pairs.
+fn circuit_frames(part: Part, playground: &Playground, pairs: usize) -> Result<Vec<Frame>> {
+    let coords = playground.coords();
+    let position = project(coords);
+    let mut frames = vec![];
+    let mut draw = |&(distance, a, b): &Pair, connection: Connection, circuits: &[Circuit]| {
+        let mut rows = vec![vec!['.'; COLUMNS as usize]; ROWS as usize];
+        let linked = circuits
+            .iter()
+            .filter(|c| c.size() > 1)
+            .flat_map(Circuit::coords)
+            .collect::<HashSet<_>>();
+        for coord in coords {
+            let (x, y) = position(*coord);
+            rows[y][x] = if linked.contains(coord) { '#' } else { 'o' };
+        }
+        for coord in [a, b] {
+            let (x, y) = position(coord);
+            rows[y][x] = '@';
+        }
+        let what = match connection {
+            Connection::New => "started a circuit",
+            Connection::Joined => "joined a circuit",
+            Connection::Unchanged => "already in the same circuit",
+            Connection::Merged => "merged two circuits",
+        };
+        let sizes = circuits
+            .iter()
+            .map(Circuit::size)
+            .sorted()
+            .rev()
+            .collect_vec();
+        let caption = format!("Pair {}: {}", frames.len() + 1, what);
+        let frame = Frame::new(caption, rows)
+            .with_counter("distance", format!("{:.1}", distance))
+            .with_counter("circuits", circuits.len())
+            .with_counter("largest", sizes.first().copied().unwrap_or(0))
+            .with_counter(
+                "three largest",
+                sizes
+                    .iter()
+                    .take(3)
+                    .map(|&size| size as i64)
+                    .product::<i64>(),
+            );
+        frames.push(frame);
+    };
+    match part {
+        Part::Part1 => {
+            build_circuits(playground, pairs, &mut draw)?;
+        }
+        Part::Part2 => {
+            build_circuits_pt2(playground, &mut draw)?;
+        }
+    }
+    Ok(frames)
+}
+
+impl Visualize for DayEight {
+    fn frames(&self, part: Part, playground: &Playground) -> Result<Vec<Frame>> {
+        circuit_frames(part, playground, 1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::advent_day::AdventDay;
+    use crate::utils::examples::load_examples;
+    use crate::utils::models::{CalendarDay, Day, Year};
+
+    #[test]
+    fn test_frames() {
+        let example = &load_examples(CalendarDay::new(Year::new(2025), Day::new(8)))[0];
+        let playground = DayEight.parse(&example.input).unwrap();
+        // The puzzle's example only connects the 10 closest pairs
+        let frames = circuit_frames(Part::Part1, &playground, 10).unwrap();
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0].caption, "Pair 1: started a circuit");
+        assert_eq!(frames[3].caption, "Pair 4: already in the same circuit");
+        assert_eq!(
+            frames[9].counters[3],
+            ("three largest".to_string(), "40".to_string())
+        );
+
+        let frames = Visualize::frames(&DayEight, Part::Part2, &playground).unwrap();
+        assert_eq!(frames[3].caption, "Pair 4: already in the same circuit");
+        let last = frames.last().unwrap();
+        assert_eq!(
+            last.caption,
+            format!("Pair {}: merged two circuits", frames.len())
+        );
+        assert_eq!(last.counters[1], ("circuits".to_string(), "1".to_string()));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::coordinates::{Area, Coordinate2D};
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::progress::{Cancelled, Progress};
+use crate::utils::registry::RegisteredDay;
+use anyhow::{anyhow, Result};
+use geo::{BoundingRect, Contains, Intersects, Rect};
+use geo::{Point, Polygon};
+use itertools::Itertools;
+use rayon::iter::ParallelIterator;
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+pub struct DayNine;
+
+/// How many rectangles are checked at a time in part 2.
+const BATCH: usize = 1024;
+
+inventory::submit! {
+    RegisteredDay::new(2025, 9, "Movie Theater", &DayNine)
+}
+
+impl AdventDay for DayNine {
+    type Input = Vec<Coordinate2D>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Coordinate2D>> {
+        let coords = input
+            .split_whitespace()
+            .map(|raw| Coordinate2D::try_from(raw).map_err(|e| Diagnostic::new(input, raw, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if coords.len() < 2 {
+            return Err(Diagnostic::at_end(
+                input.trim_end(),
+                format!(
+                    "Expected at least 2 red tiles to make a rectangle, found {}",
+                    coords.len()
+                ),
+            )
+            .into());
+        }
+        Ok(coords)
+    }
+
+    fn part_1(&self, coords: &Vec<Coordinate2D>) -> Result<Answer> {
+        tracing::info!("Day 9: Part 1");
+        Ok(get_biggest_rectangle(coords)?.into())
+    }
+
+    fn part_2(&self, coords: &Vec<Coordinate2D>) -> Result<Answer> {
+        tracing::info!("Day 9: Part 2");
+        Ok(get_biggest_rectangle_pt2(coords)?.into())
+    }
+}
+
+struct Rectangle {
+    point_1: Coordinate2D,
+    point_2: Coordinate2D,
+}
+
+impl Rectangle {
+    fn area(&self) -> i64 {
+        self.point_1.area(self.point_2)
+    }
+
+    fn corners(&self) -> Vec<Coordinate2D> {
+        vec![
+            self.point_1,
+            self.point_2,
+            Coordinate2D {
+                x: self.point_1.x,
+                y: self.point_2.y,
+            },
+            Coordinate2D {
+                x: self.point_2.x,
+                y: self.point_1.y,
+            },
+        ]
+    }
+}
+
+fn get_biggest_rectangle(coords: &[Coordinate2D]) -> Result<i64> {
+    let rectangle = coords
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.area(*b))
+        .max()
+        .ok_or(anyhow!("No max found."))?;
+
+    Ok(rectangle)
+}
+
+fn get_biggest_rectangle_pt2(coords: &[Coordinate2D]) -> Result<i64> {
+    let mut poly_points: Vec<Point<f64>> = coords
+        .iter()
+        .map(|c| Point::new(c.x as f64, c.y as f64))
+        .collect();
+    if poly_points.first() != poly_points.last() {
+        poly_points.push(*poly_points.first().unwrap());
+    }
+    let poly = Polygon::new(poly_points.into_iter().collect(), vec![]);
+    let poly_bbox = poly.bounding_rect().expect("Couldn't build bounding box");
+
+    let pairs = coords.len() * coords.len().saturating_sub(1) / 2;
+    let progress = Progress::new(pairs as u64, "rectangles checked");
+    let mut rectangles = coords
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| Rectangle {
+            point_1: *a,
+            point_2: *b,
+        })
+        .collect_vec();
+    progress.check()?;
+    rectangles.par_sort_unstable_by(|a, b| b.area().cmp(&a.area()));
+    progress.check()?;
+    let fits = |rectangle: &&Rectangle| {
+        let candidate_rect = Rect::new(
+            Point::new(rectangle.point_1.x as f64, rectangle.point_1.y as f64),
+            Point::new(rectangle.point_2.x as f64, rectangle.point_2.y as f64),
+        );
+        rectangle
+            .corners()
+            .iter()
+            .all(|p| poly.intersects(&Point::new(p.x as f64, p.y as f64)))
+            && poly_bbox.contains(&candidate_rect)
+            && poly.contains(&candidate_rect)
+    };
+    // Checked in batches, so the threads only touch the shared progress once per batch
+    let best_rectangle = rectangles
+        .par_chunks(BATCH)
+        .find_map_first(|batch| {
+            if progress.cancelled() {
+                return Some(Err(Cancelled));
+            }
+            let found = batch.iter().find(fits);
+            progress.inc(batch.len() as u64);
+            found.map(Ok)
+        })
+        .ok_or(anyhow!("No valid rectangle found."))??;
+
+    Ok(best_rectangle.area())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_too_few_tiles() {
+        for input in ["", "\n", "7,1\n"] {
+            let error = DayNine.parse(input).unwrap_err();
+            assert!(error.downcast_ref::<Diagnostic>().is_some());
+        }
+    }
+
+    crate::utils::examples::example_tests!(2025, 9, DayNine);
+}
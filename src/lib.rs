@@ -0,0 +1,17 @@
+//! Solutions to Advent of Code 2025, along with the utilities they share (grids, coordinates,
+//! input loading) and the runner behind the `advent_of_code_25` binary. Each year's days live
+//! in their own module under [`days`], e.g. `days::y2025`.
+//!
+//! Every day's solver implements [`utils::advent_day::AdventDay`]:
+//!
+//! ```
+//! use advent_of_code_25::days::y2025::day_1::run::DayOne;
+//! use advent_of_code_25::utils::advent_day::AdventDay;
+//!
+//! let cracker = DayOne.parse("L68 L30 R48 L5 R60 L55 L1 L99 R14 L82").unwrap();
+//! assert_eq!(DayOne.part_1(&cracker).unwrap().to_string(), "3");
+//! ```
+
+pub mod days;
+pub mod runner;
+pub mod utils;
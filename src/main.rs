@@ -1,48 +1,350 @@
-mod days;
-mod utils;
-
-use crate::days::day_1::run::DayOne;
-use crate::days::day_2::run::DayTwo;
-use crate::days::day_3::run::DayThree;
-use crate::days::day_4::run::DayFour;
-use crate::days::day_5::run::DayFive;
-use crate::days::day_6::run::DaySix;
-use crate::days::day_7::run::DaySeven;
-use crate::days::day_8::run::DayEight;
-use crate::days::day_9::run::DayNine;
-use crate::utils::advent_day::AdventDay;
-use crate::utils::models::{Day, Part};
-use anyhow::Result;
-use clap::Parser;
-use tracing_subscriber::FmtSubscriber;
+use advent_of_code_25::runner::bench::bench_selection;
+use advent_of_code_25::runner::client::{
+    load_session, AocClient, RateLimiter, DEFAULT_BASE_URL, MIN_REQUEST_INTERVAL,
+};
+use advent_of_code_25::runner::fetch::fetch_days;
+use advent_of_code_25::runner::guard::parse_budget;
+use advent_of_code_25::runner::inputs::{days_or_all, InputSelection};
+use advent_of_code_25::runner::logging;
+use advent_of_code_25::runner::output::OutputFormat;
+use advent_of_code_25::runner::submit::{solve_and_submit, SubmissionLog};
+use advent_of_code_25::runner::table::render_table;
+use advent_of_code_25::runner::verify::run_verify;
+use advent_of_code_25::runner::visualize::FrameFormat;
+use advent_of_code_25::runner::{run_selection, scaffold, tui, watch};
+use advent_of_code_25::utils::models::{
+    CalendarDay, Day, DaySelection, InputSource, Part, PartSelection, Year,
+};
+use advent_of_code_25::utils::registry;
+use advent_of_code_25::utils::registry::RegisteredDay;
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand};
+use rayon::ThreadPoolBuilder;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const BENCH_HISTORY: &str = ".aoc/bench_history.jsonl";
+const SUBMISSIONS: &str = ".aoc/submissions.json";
 
 #[derive(Parser, Debug)]
-#[command(author, version, about)]
+#[command(author, version, about, args_conflicts_with_subcommands = true)]
+#[command(subcommand_negates_reqs = true)]
 struct Cli {
-    /// The day to run (e.g., -d 1, --day 3)
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How to print the results
+    #[arg(short, long, default_value = "text")]
+    format: OutputFormat,
+
+    /// The number of threads to run days on, also used by days that parallelise internally
+    /// (defaults to one per CPU)
+    #[arg(short = 'j', long)]
+    threads: Option<usize>,
+
+    /// Keep running, and re-run whenever a file under the selected days' `inputs/YYYY/day_N/`
+    /// changes
     #[arg(short, long)]
-    day: Day,
+    watch: bool,
+
+    /// Give up on any part still parsing or solving after this long (e.g., 30s, 500ms, 2m).
+    /// It's reported as failed, and the rest of the days carry on
+    #[arg(short, long, value_parser = parse_budget)]
+    timeout: Option<Duration>,
+
+    /// Write frames showing how each selected day/part is solved into this directory, under
+    /// `YYYY/day_N/part_P/`, for days with a visualization
+    #[arg(long, conflicts_with = "watch")]
+    visualize: Option<PathBuf>,
+
+    /// How to write the frames for `--visualize`
+    #[arg(long, default_value = "ascii", requires = "visualize")]
+    frame_format: FrameFormat,
+
+    /// The year whose days to use (defaults to the most recent year with solutions)
+    #[arg(short, long, global = true, env = "AOC_YEAR")]
+    year: Option<Year>,
+
+    /// Log more (-v for debug, -vv for trace). `RUST_LOG` takes precedence if set
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Log less (-q for warnings only, -qq for errors only, -qqq for nothing)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true, conflicts_with = "verbose")]
+    quiet: u8,
+
+    /// Write a Chrome trace of the day, part and phase spans to this file, for chrome://tracing
+    /// or Perfetto
+    #[arg(long, global = true)]
+    trace_chrome: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Time repeated runs of a day/part, reporting parse and solve times separately
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// The number of timed runs (e.g., -n 50)
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: usize,
+
+        /// The number of untimed runs before timing starts
+        #[arg(short, long, default_value_t = 2)]
+        warmup: usize,
+
+        /// Compare against the previous recorded run on the same input, failing on any
+        /// significant slowdown
+        #[arg(short, long)]
+        compare: bool,
+
+        /// Write a report of every recorded run to this file (.md or .html)
+        #[arg(short, long)]
+        report: Option<PathBuf>,
+    },
+    /// Download puzzle inputs into `inputs/`, skipping any that are already downloaded
+    Fetch {
+        /// The days to download (e.g., fetch 1 2 3)
+        #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+
+        #[command(flatten)]
+        site: Site,
+    },
+    /// List every registered day, or only those of `--year`
+    List,
+    /// Create the module skeleton and inputs directory for a new day
+    NewDay {
+        /// The day to create (e.g., 10)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle's title, shown by `list` (e.g., --name "Factory")
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+    /// Solve a day/part and submit the answer, unless earlier submissions rule it out
+    Submit {
+        /// The day to submit (e.g., -d 3)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part to submit (e.g., -p 1)
+        #[arg(short, long)]
+        part: Part,
+
+        /// Read the input from this file instead of `inputs/`, or from stdin with `-`
+        #[arg(short, long)]
+        input: Option<InputSource>,
+
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Step forwards and backwards through a day's simulation in the terminal, for days with a
+    /// visualization
+    Step {
+        /// The day to step through (e.g., -d 1)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part to step through (e.g., -p 2)
+        #[arg(short, long)]
+        part: Part,
+
+        /// Read the input from this file instead of `inputs/`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
 
-    /// The part to run (e.g., -p 1, --part 2)
+        /// Step through the puzzle's example N instead of the real input (e.g., --example 1)
+        #[arg(short, long, conflicts_with = "input")]
+        example: Option<u8>,
+    },
+    /// Run days and check their answers against a file of expected answers
+    Verify {
+        /// The days to verify, as a list of days or ranges (defaults to every day)
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+
+        /// The part to verify (e.g., -p 1, --part 2, -p both)
+        #[arg(short, long, default_value = "both")]
+        part: PartSelection,
+
+        /// The file of expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Give up on any part still parsing or solving after this long (e.g., 30s, 500ms, 2m).
+        /// It's reported as failed
+        #[arg(short, long, value_parser = parse_budget)]
+        timeout: Option<Duration>,
+    },
+}
+
+#[derive(Args, Debug)]
+struct Selection {
+    /// The days to run, as a list of days or ranges (e.g., -d 1, --day 3, -d 1-5,8)
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<DaySelection>,
+
+    /// The part to run (e.g., -p 1, --part 2, -p both)
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+
+    /// Run every day
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+
+    /// Read the input from this file instead of `inputs/`, or from stdin with `-`
     #[arg(short, long)]
-    part: Part,
+    input: Option<InputSource>,
+
+    /// Run against the puzzle's example N instead of the real input (e.g., --example 1)
+    #[arg(short, long, conflicts_with = "input")]
+    example: Option<u8>,
 }
 
-fn main() -> Result<()> {
-    FmtSubscriber::builder().init();
+#[derive(Args, Debug)]
+struct Site {
+    /// The base URL of the puzzle site, e.g. a local stand-in for testing
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The session token (read from ~/.config/aoc/session if not set)
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
 
+impl Site {
+    fn client(&self) -> Result<AocClient> {
+        let limiter = RateLimiter::new(".aoc/last_request".into(), MIN_REQUEST_INTERVAL);
+        Ok(AocClient::new(
+            &self.base_url,
+            load_session(self.session.as_deref())?,
+            limiter,
+        ))
+    }
+}
+
+impl Selection {
+    fn inputs(&self) -> InputSelection {
+        InputSelection {
+            days: self.day.clone(),
+            parts: self.part,
+            input: self.input.clone(),
+            example: self.example,
+        }
+    }
+}
+
+/// The picked year, or the most recent one with registered days.
+fn year_or_latest(year: Option<Year>) -> Result<Year> {
+    year.or_else(registry::latest_year)
+        .context("No days are registered, so pick a year with --year")
+}
+
+fn list(year: Option<Year>) {
+    let years = match year {
+        Some(year) => vec![year],
+        None => registry::years(),
+    };
+    let rows = years
+        .into_iter()
+        .flat_map(registry::days)
+        .map(
+            |RegisteredDay {
+                 year, day, name, ..
+             }| { vec![year.to_string(), day.to_string(), name.to_string()] },
+        )
+        .collect::<Vec<_>>();
+    println!("{}", render_table(&["Year", "Day", "Name"], &rows));
+}
+
+fn main() -> Result<()> {
     let cli = Cli::parse();
+    let verbosity = cli.verbose as i8 - cli.quiet as i8;
+    let _trace = logging::init(verbosity, cli.trace_chrome.as_deref())?;
+
+    let year = year_or_latest(cli.year)?;
+    // One pool for everything, so parts run on their own threads against a `--timeout` still
+    // parallelise on the pool sized by `--threads`
+    ThreadPoolBuilder::new()
+        .num_threads(cli.threads.unwrap_or(0))
+        .build_global()?;
 
-    match &cli.day {
-        Day::Day1 => DayOne.run(cli.part)?,
-        Day::Day2 => DayTwo.run(cli.part)?,
-        Day::Day3 => DayThree.run(cli.part)?,
-        Day::Day4 => DayFour.run(cli.part)?,
-        Day::Day5 => DayFive.run(cli.part)?,
-        Day::Day6 => DaySix.run(cli.part)?,
-        Day::Day7 => DaySeven.run(cli.part)?,
-        Day::Day8 => DayEight.run(cli.part)?,
-        Day::Day9 => DayNine.run(cli.part)?,
+    match &cli.command {
+        None if cli.watch => watch::watch(year, &cli.selection.inputs(), cli.format, cli.timeout),
+        None => run_selection(
+            year,
+            &cli.selection.inputs(),
+            cli.format,
+            cli.timeout,
+            cli.visualize.as_deref().map(|dir| (dir, cli.frame_format)),
+        ),
+        Some(Command::Bench {
+            selection,
+            runs,
+            warmup,
+            compare,
+            report,
+        }) => bench_selection(
+            year,
+            &selection.inputs(),
+            *runs,
+            *warmup,
+            *compare,
+            Path::new(BENCH_HISTORY),
+            report.as_deref(),
+        ),
+        Some(Command::Fetch { days, site }) => {
+            let days = days.iter().map(|&day| Day::new(day)).collect::<Vec<_>>();
+            fetch_days(&site.client()?, year, &days)
+        }
+        Some(Command::List) => {
+            // Every year unless one was picked
+            list(cli.year);
+            Ok(())
+        }
+        Some(Command::NewDay { day, name }) => {
+            let day = CalendarDay::new(year, Day::new(*day));
+            let name = name.clone().unwrap_or_else(|| format!("Day {}", day.day));
+            for path in scaffold::new_day(Path::new("."), day, &name)? {
+                println!("Created {}", path.display());
+            }
+            Ok(())
+        }
+        Some(Command::Submit {
+            day,
+            part,
+            input,
+            site,
+        }) => solve_and_submit(
+            &site.client()?,
+            &mut SubmissionLog::load(Path::new(SUBMISSIONS))?,
+            CalendarDay::new(year, Day::new(*day)),
+            *part,
+            input.as_ref(),
+        ),
+        Some(Command::Step {
+            day,
+            part,
+            input,
+            example,
+        }) => tui::step(year, Day::new(*day), *part, input.as_deref(), *example),
+        Some(Command::Verify {
+            day,
+            part,
+            answers,
+            timeout,
+        }) => run_verify(
+            year,
+            days_or_all(year, day.as_ref())?,
+            *part,
+            answers,
+            *timeout,
+        ),
     }
-    Ok(())
 }
@@ -0,0 +1,193 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Whether allocations are being counted, i.e. the crate was built with the
+/// `count-allocations` feature.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// What a day/part allocated while it ran.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most heap in use at once, including anything allocated before the part started
+    pub peak_bytes: u64,
+}
+
+impl AllocStats {
+    /// Combines the stats of two phases that ran one after the other.
+    pub fn then(self, next: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + next.allocations,
+            bytes: self.bytes + next.bytes,
+            peak_bytes: self.peak_bytes.max(next.peak_bytes),
+        }
+    }
+}
+
+/// Counters read when a measurement starts.
+pub struct Measurement {
+    allocations: u64,
+    bytes: u64,
+}
+
+/// Wraps the system allocator, counting allocations and tracking the heap in use.
+pub struct CountingAllocator {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    current: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            current: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    fn record_alloc(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size as u64, Ordering::Relaxed);
+        let current = self.current.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.current.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+
+    /// Starts a measurement, resetting the peak to the heap currently in use.
+    pub fn start(&self) -> Measurement {
+        self.peak
+            .store(self.current.load(Ordering::Relaxed), Ordering::Relaxed);
+        Measurement {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn finish(&self, start: Measurement) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed) - start.allocations,
+            bytes: self.bytes.load(Ordering::Relaxed) - start.bytes,
+            peak_bytes: self.peak.load(Ordering::Relaxed),
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Counted as freeing the old block and allocating the new one
+            self.record_dealloc(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f`, returning what it allocated if allocations are being counted.
+///
+/// The counters are global, so anything else running at the same time is counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let start = ALLOCATOR.start();
+        let result = f();
+        (result, Some(ALLOCATOR.finish(start)))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    (f(), None)
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{:.2} {}", value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_allocator() {
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+        unsafe {
+            let first = allocator.alloc(layout);
+            let start = allocator.start();
+            let second = allocator.alloc(layout);
+            let second = allocator.realloc(second, layout, 4096);
+            allocator.dealloc(second, Layout::from_size_align(4096, 8).unwrap());
+            let stats = allocator.finish(start);
+            allocator.dealloc(first, layout);
+
+            assert_eq!(
+                stats,
+                AllocStats {
+                    allocations: 2,
+                    bytes: 1024 + 4096,
+                    peak_bytes: 1024 + 4096,
+                }
+            );
+        }
+        assert_eq!(allocator.current.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+}
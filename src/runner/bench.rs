@@ -0,0 +1,185 @@
+use crate::runner::compare::{self, Verdict};
+use crate::runner::history::{current_commit, BenchHistory, BenchRecord};
+use crate::runner::inputs::InputSelection;
+use crate::runner::report::{self, ReportFormat};
+use crate::runner::table::render_table;
+use crate::runner::{run_part, PartRun};
+use crate::utils::advent_day::Solver;
+use crate::utils::load::PuzzleInput;
+use crate::utils::models::{CalendarDay, Day, Part, Year};
+use crate::utils::registry;
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const HEADERS: [&str; 7] = ["Day", "Part", "Phase", "Min", "Median", "Mean", "P95"];
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let sorted = samples.iter().copied().sorted().collect_vec();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        // Nearest-rank percentile
+        let p95_rank = (sorted.len() as f64 * 0.95).ceil() as usize;
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+            p95: sorted[p95_rank.max(1) - 1],
+        })
+    }
+}
+
+/// Timings for repeated runs of a single day/part, split by phase.
+pub struct BenchResult {
+    pub day: Day,
+    pub part: Part,
+    pub input_hash: String,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub parse_samples: Vec<Duration>,
+    pub solve_samples: Vec<Duration>,
+}
+
+pub fn bench_part(
+    solver: &dyn Solver,
+    day: Day,
+    part: Part,
+    input: &PuzzleInput,
+    runs: usize,
+    warmup: usize,
+) -> Result<BenchResult> {
+    for _ in 0..warmup {
+        run_part(solver, day, part, input)?;
+    }
+    let samples: Vec<PartRun> = (0..runs.max(1))
+        .map(|_| run_part(solver, day, part, input))
+        .collect::<Result<_>>()?;
+    let parse_samples = samples.iter().map(|s| s.parse_time).collect_vec();
+    let solve_samples = samples.iter().map(|s| s.solve_time).collect_vec();
+    Ok(BenchResult {
+        day,
+        part,
+        input_hash: input.hash.clone(),
+        parse: Stats::from_samples(&parse_samples).expect("at least one sample"),
+        solve: Stats::from_samples(&solve_samples).expect("at least one sample"),
+        parse_samples,
+        solve_samples,
+    })
+}
+
+/// Benchmarks every selected day/part, appending the timings to the history at
+/// `history_path`. With `compare_previous`, each timing is compared against the previous run
+/// on the same input, failing on any significant slowdown. With `report`, a report of the
+/// whole history is written there.
+pub fn bench_selection(
+    year: Year,
+    selection: &InputSelection,
+    runs: usize,
+    warmup: usize,
+    compare_previous: bool,
+    history_path: &Path,
+    report: Option<&Path>,
+) -> Result<()> {
+    let report_format = report.map(ReportFormat::from_path).transpose()?;
+    let mut results = vec![];
+    for (day, inputs) in selection.load(year)? {
+        let solver = registry::solver(CalendarDay::new(year, day))?;
+        for (part, input) in inputs {
+            results.push(bench_part(solver, day, part, &input, runs, warmup)?);
+        }
+    }
+    println!("{}", render(&results));
+
+    let mut history = BenchHistory::load(history_path)?;
+    let commit = current_commit();
+    let records = results
+        .iter()
+        .map(|result| BenchRecord::new(&commit, year, result))
+        .collect_vec();
+    let comparisons = records
+        .iter()
+        .filter_map(|record| Some(compare::compare(history.previous(record)?, record)))
+        .flatten()
+        .collect_vec();
+    history.append(&records)?;
+
+    if let (Some(path), Some(format)) = (report, report_format) {
+        fs::write(path, report::render(&history.records, format))?;
+        println!("Wrote report to {}", path.display());
+    }
+    if compare_previous {
+        if comparisons.is_empty() {
+            println!("No earlier runs on the same inputs to compare against");
+            return Ok(());
+        }
+        println!("{}", compare::render(&comparisons));
+        let slower = comparisons
+            .iter()
+            .filter(|c| c.verdict == Verdict::Slower)
+            .count();
+        if slower > 0 {
+            bail!("{} timing(s) were significantly slower", slower);
+        }
+    }
+    Ok(())
+}
+
+fn rows(result: &BenchResult) -> Vec<Vec<String>> {
+    [("parse", result.parse), ("solve", result.solve)]
+        .into_iter()
+        .map(|(phase, stats)| {
+            vec![
+                result.day.number().to_string(),
+                result.part.number().to_string(),
+                phase.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.p95),
+            ]
+        })
+        .collect()
+}
+
+/// Renders a table of benchmark results, one row per day/part/phase.
+pub fn render(results: &[BenchResult]) -> String {
+    render_table(&HEADERS, &results.iter().flat_map(rows).collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = (1..=20).map(Duration::from_millis).collect_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let stats = Stats::from_samples(&[Duration::from_millis(3)]).unwrap();
+        assert_eq!(stats.p95, Duration::from_millis(3));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+}
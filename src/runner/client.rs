@@ -0,0 +1,226 @@
+use crate::utils::models::CalendarDay;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = "github.com/MattBarkway/advent-of-code-25";
+
+/// Finds the session token, preferring `session` (from `--session` or `AOC_SESSION`)
+/// over the config file at `~/.config/aoc/session`.
+pub fn load_session(session: Option<&str>) -> Result<String> {
+    if let Some(session) = session {
+        return Ok(session.trim().to_string());
+    }
+    let home = std::env::var("HOME").context("No session token: set AOC_SESSION or HOME")?;
+    let path = Path::new(&home).join(".config/aoc/session");
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No session token: set AOC_SESSION or write it to {}",
+            path.display()
+        )
+    })?;
+    Ok(session.trim().to_string())
+}
+
+/// Spaces out requests, including across runs, by recording the time of the
+/// last request in `state_file`.
+pub struct RateLimiter {
+    state_file: PathBuf,
+    min_interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(state_file: PathBuf, min_interval: Duration) -> Self {
+        Self {
+            state_file,
+            min_interval,
+        }
+    }
+
+    fn last_request(&self) -> Option<Duration> {
+        let nanos = fs::read_to_string(&self.state_file).ok()?;
+        Some(Duration::from_nanos(nanos.trim().parse().ok()?))
+    }
+
+    /// Blocks until a request is allowed, then records it.
+    pub fn wait(&self) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        if let Some(last) = self.last_request() {
+            let elapsed = now.saturating_sub(last);
+            if elapsed < self.min_interval {
+                let remaining = self.min_interval - elapsed;
+                tracing::info!("Rate limited, waiting {:.1?}", remaining);
+                thread::sleep(remaining);
+            }
+        }
+        if let Some(parent) = self.state_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::write(&self.state_file, now.as_nanos().to_string())?;
+        Ok(())
+    }
+}
+
+/// A client for the puzzle site, or anything serving the same endpoints.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    limiter: RateLimiter,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: String, limiter: RateLimiter) -> Self {
+        let config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            limiter,
+            agent: ureq::Agent::new_with_config(config),
+        }
+    }
+
+    pub fn day_url(&self, day: CalendarDay) -> String {
+        format!("{}/{}/day/{}", self.base_url, day.year, day.day)
+    }
+
+    pub fn get(&self, url: &str) -> Result<String> {
+        self.limiter.wait()?;
+        tracing::info!("GET {}", url);
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("Request to {} failed", url))?;
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+        if !status.is_success() {
+            bail!("Request to {} failed with {}: {}", url, status, body.trim());
+        }
+        Ok(body)
+    }
+
+    pub fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.limiter.wait()?;
+        tracing::info!("POST {}", url);
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .with_context(|| format!("Request to {} failed", url))?;
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+        if !status.is_success() {
+            bail!("Request to {} failed with {}: {}", url, status, body.trim());
+        }
+        Ok(body)
+    }
+}
+
+/// A stand-in HTTP server for tests, answering each request with the next canned
+/// body and recording the requests it receives.
+#[cfg(test)]
+pub mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    /// Starts a server that answers `responses.len()` requests with status 200, returning
+    /// its base URL and a channel of the requests it received.
+    pub fn serve(responses: Vec<&'static str>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender
+                    .send(Request {
+                        head,
+                        body: String::from_utf8(request_body).unwrap(),
+                    })
+                    .unwrap();
+            }
+        });
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_rate_limiter_spaces_out_requests() {
+        let state_file =
+            std::env::temp_dir().join(format!("aoc_rate_limit_{}", std::process::id()));
+        let limiter = RateLimiter::new(state_file.clone(), Duration::from_millis(200));
+        let start = Instant::now();
+        limiter.wait().unwrap();
+        limiter.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        fs::remove_file(state_file).unwrap();
+    }
+
+    #[test]
+    fn test_get_sends_session() {
+        let (url, requests) = test_server::serve(vec!["hello"]);
+        let state_file = std::env::temp_dir().join(format!("aoc_get_{}", std::process::id()));
+        let client = AocClient::new(
+            &url,
+            "secret".to_string(),
+            RateLimiter::new(state_file.clone(), Duration::ZERO),
+        );
+        assert_eq!(client.get(&format!("{}/ping", url)).unwrap(), "hello");
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("GET /ping"));
+        assert!(request.head.contains("session=secret"));
+        assert!(request.body.is_empty());
+        fs::remove_file(state_file).unwrap();
+    }
+}
@@ -0,0 +1,275 @@
+use crate::runner::history::BenchRecord;
+use crate::runner::table::render_table;
+use std::f64::consts::PI;
+use std::fmt;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Changes with a p-value below this are treated as real rather than noise.
+pub const SIGNIFICANCE: f64 = 0.05;
+
+/// The smallest relative change in the mean worth reporting, however significant. Long runs
+/// make tiny differences significant, and those are usually just noise from the machine.
+pub const MIN_CHANGE: f64 = 0.05;
+
+const HEADERS: [&str; 8] = [
+    "Day", "Part", "Phase", "Before", "After", "Change", "p", "Verdict",
+];
+
+/// The result of Welch's t-test, which doesn't assume the two samples have equal variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welch {
+    pub t: f64,
+    pub df: f64,
+    /// The two-sided p-value
+    pub p: f64,
+}
+
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+/// Tests whether `a` and `b` have different means. Returns `None` if either has fewer
+/// than two samples, or neither varies at all.
+pub fn welch_t_test(a: &[f64], b: &[f64]) -> Option<Welch> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, var_a) = mean_and_variance(a);
+    let (mean_b, var_b) = mean_and_variance(b);
+    let (se_a, se_b) = (var_a / a.len() as f64, var_b / b.len() as f64);
+    if se_a + se_b == 0.0 {
+        return None;
+    }
+    let t = (mean_b - mean_a) / (se_a + se_b).sqrt();
+    let df = (se_a + se_b).powi(2)
+        / (se_a.powi(2) / (a.len() as f64 - 1.0) + se_b.powi(2) / (b.len() as f64 - 1.0));
+    let p = incomplete_beta(df / 2.0, 0.5, df / (df + t * t));
+    Some(Welch { t, df, p })
+}
+
+/// The log of the gamma function, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// The regularised incomplete beta function, I_x(a, b).
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly on one side of this point, so use the
+    // symmetry I_x(a, b) = 1 - I_(1-x)(b, a) on the other
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Evaluates the continued fraction for the incomplete beta function by Lentz's method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut result = d;
+    for m in 1..300 {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        for numerator in [
+            m * (b - m) * x / ((a + m2 - 1.0) * (a + m2)),
+            -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            result *= d * c;
+        }
+        if (d * c - 1.0).abs() < 1e-12 {
+            break;
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Slower,
+    Faster,
+    /// Not a significant change, or too few samples to tell
+    Unchanged,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Slower => write!(f, "SLOWER"),
+            Verdict::Faster => write!(f, "faster"),
+            Verdict::Unchanged => write!(f, "-"),
+        }
+    }
+}
+
+/// How one phase of a day/part changed between two recorded runs.
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub phase: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+    pub test: Option<Welch>,
+    pub verdict: Verdict,
+}
+
+fn mean(samples: &[f64]) -> Duration {
+    Duration::from_nanos((samples.iter().sum::<f64>() / samples.len().max(1) as f64) as u64)
+}
+
+/// How much `after` changed relative to `before`, e.g. 0.1 for 10% slower.
+fn relative_change(before: Duration, after: Duration) -> f64 {
+    after.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0
+}
+
+/// Compares the parse and solve timings of `current` against an earlier `previous` record.
+pub fn compare(previous: &BenchRecord, current: &BenchRecord) -> Vec<Comparison> {
+    let as_f64 = |samples: &[u64]| samples.iter().map(|&ns| ns as f64).collect::<Vec<_>>();
+    [
+        ("parse", &previous.parse_ns, &current.parse_ns),
+        ("solve", &previous.solve_ns, &current.solve_ns),
+    ]
+    .into_iter()
+    .map(|(phase, before, after)| {
+        let (before, after) = (as_f64(before), as_f64(after));
+        let test = welch_t_test(&before, &after);
+        let (before, after) = (mean(&before), mean(&after));
+        let change = relative_change(before, after);
+        let verdict = match test {
+            Some(test) if test.p >= SIGNIFICANCE || change.abs() < MIN_CHANGE => Verdict::Unchanged,
+            Some(test) if test.t > 0.0 => Verdict::Slower,
+            Some(_) => Verdict::Faster,
+            None => Verdict::Unchanged,
+        };
+        Comparison {
+            day: current.day,
+            part: current.part,
+            phase,
+            before,
+            after,
+            test,
+            verdict,
+        }
+    })
+    .collect()
+}
+
+/// Renders a table comparing mean timings, one row per day/part/phase.
+pub fn render(comparisons: &[Comparison]) -> String {
+    let rows = comparisons
+        .iter()
+        .map(|c| {
+            vec![
+                c.day.to_string(),
+                c.part.to_string(),
+                c.phase.to_string(),
+                format!("{:.2?}", c.before),
+                format!("{:.2?}", c.after),
+                format!("{:+.1}%", relative_change(c.before, c.after) * 100.0),
+                c.test
+                    .map_or("-".to_string(), |test| format!("{:.3}", test.p)),
+                c.verdict.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    render_table(&HEADERS, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::history::test_record;
+
+    #[test]
+    fn test_p_value() {
+        // From a table of Student's t distribution: P(|T| > 2.228) = 0.05 with 10 degrees of freedom
+        let p = incomplete_beta(5.0, 0.5, 10.0 / (10.0 + 2.228 * 2.228));
+        assert!((p - 0.05).abs() < 1e-3, "p = {}", p);
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_welch_t_test() {
+        let before = [100.0, 102.0, 98.0, 101.0, 99.0];
+        let slower = [120.0, 122.0, 118.0, 121.0, 119.0];
+        let test = welch_t_test(&before, &slower).unwrap();
+        assert!(test.t > 0.0 && test.p < 0.001);
+
+        let noisy = [99.0, 103.0, 97.0, 100.0, 101.0];
+        assert!(welch_t_test(&before, &noisy).unwrap().p > SIGNIFICANCE);
+        assert!(welch_t_test(&[1.0], &slower).is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let comparisons = compare(
+            &test_record("abc", 2, vec![1000, 1010, 990, 1000]),
+            &test_record("def", 2, vec![2000, 2020, 1980, 2000]),
+        );
+        assert_eq!(comparisons[0].verdict, Verdict::Unchanged);
+        assert_eq!(comparisons[1].verdict, Verdict::Slower);
+        assert!(render(&comparisons).contains("+100.0%"));
+    }
+
+    #[test]
+    fn test_compare_ignores_small_changes() {
+        // Significant, as the samples barely vary, but only 2% slower
+        let before = test_record("abc", 2, vec![1000, 1001, 999, 1000, 1000, 1001, 999, 1000]);
+        let after = test_record(
+            "def",
+            2,
+            vec![1020, 1021, 1019, 1020, 1020, 1021, 1019, 1020],
+        );
+        let comparisons = compare(&before, &after);
+        assert!(comparisons[1].test.unwrap().p < SIGNIFICANCE);
+        assert_eq!(comparisons[1].verdict, Verdict::Unchanged);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::runner::client::AocClient;
+use crate::utils::load::input_path;
+use crate::utils::models::{CalendarDay, Day, Year};
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub enum Fetched {
+    /// The input was already downloaded, so no request was made
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads a day's input into `inputs/` under `root`, unless it's already there.
+pub fn fetch_input(client: &AocClient, root: &Path, day: CalendarDay) -> Result<Fetched> {
+    let path = root.join(input_path(day));
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.get(&format!("{}/input", client.day_url(day)))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, input)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Downloads each of `days` of `year` into `inputs/`, saying where each was saved.
+pub fn fetch_days(client: &AocClient, year: Year, days: &[Day]) -> Result<()> {
+    for &day in days {
+        match fetch_input(client, Path::new("."), CalendarDay::new(year, day))? {
+            Fetched::Cached(path) => println!("Day {}: already cached at {}", day, path.display()),
+            Fetched::Downloaded(path) => println!("Day {}: saved to {}", day, path.display()),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::client::{test_server, RateLimiter};
+    use std::time::Duration;
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let (url, requests) = test_server::serve(vec!["1,2\n3,4\n"]);
+        let root = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let client = AocClient::new(
+            &url,
+            "secret".to_string(),
+            RateLimiter::new(root.join("inputs/.last_request"), Duration::ZERO),
+        );
+
+        let day = CalendarDay::new(Year::new(2024), Day::new(9));
+        let Fetched::Downloaded(path) = fetch_input(&client, &root, day).unwrap() else {
+            panic!("Expected a download");
+        };
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2\n3,4\n");
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("GET /2024/day/9/input"));
+
+        // The stand-in server only answers once, so a second request would fail
+        assert!(path.ends_with("inputs/2024/day_9/input.txt"));
+        assert!(matches!(
+            fetch_input(&client, &root, day).unwrap(),
+            Fetched::Cached(_)
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
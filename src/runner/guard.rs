@@ -0,0 +1,233 @@
+use crate::utils::progress::cancellable;
+use std::any::Any;
+use std::fmt;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// How long to sleep between checks on a part running against a time budget, when there's no
+/// other work to do in the meantime.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Parts that ran out of time but haven't stopped yet.
+static OVERRUNNING: AtomicUsize = AtomicUsize::new(0);
+
+/// How many parts are still running after running out of time, because they haven't (yet)
+/// noticed they were cancelled.
+pub fn overrunning() -> usize {
+    OVERRUNNING.load(Ordering::SeqCst)
+}
+
+/// Waits for parts that ran out of time to stop, so they don't compete with what runs next.
+pub fn wait_for_overrunning() {
+    if overrunning() > 0 {
+        tracing::warn!(
+            "Waiting for {} part(s) that timed out to stop",
+            overrunning()
+        );
+    }
+    while overrunning() > 0 {
+        thread::sleep(POLL_INTERVAL * 10);
+    }
+}
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+    /// The day returned an error, along with its causes
+    Error(String),
+    Panic(String),
+    /// The part was still running when its time budget ran out
+    Timeout(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "error: {}", error),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout(budget) => write!(f, "timed out after {:.2?}", budget),
+        }
+    }
+}
+
+impl Failure {
+    /// The first line of the failure, for tables. Errors such as input diagnostics can run
+    /// over several lines.
+    pub fn summary(&self) -> String {
+        self.to_string()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "(no message)".to_string()
+    }
+}
+
+fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> Result<T, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(Failure::Error(format!("{:#}", e))),
+        Err(payload) => Err(Failure::Panic(panic_message(&*payload))),
+    }
+}
+
+/// Runs `f`, turning an error or panic into a `Failure`, and giving up on it once `budget`
+/// has passed.
+///
+/// Threads can't be stopped from outside, so with a budget `f` runs on a thread of its own
+/// and is cancelled once it overruns. Jobs it reports [`Progress`] for see the cancellation
+/// and stop early; anything else carries on in the background until it finishes, counted by
+/// [`overrunning`].
+///
+/// [`Progress`]: crate::utils::progress::Progress
+pub fn guarded<T: Send + 'static>(
+    budget: Option<Duration>,
+    f: impl FnOnce() -> anyhow::Result<T> + Send + 'static,
+) -> Result<T, Failure> {
+    let Some(budget) = budget else {
+        return catch(f);
+    };
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let part_cancel = Arc::clone(&cancel);
+    // Keep the day/part spans, so the part's logs are tagged as usual
+    let span = tracing::Span::current();
+    thread::Builder::new()
+        .name("part".to_string())
+        .spawn(move || {
+            let _span = span.entered();
+            let result = cancellable(part_cancel, || catch(f));
+            // Nobody is listening any more if the budget ran out
+            if sender.send(result).is_err() {
+                OVERRUNNING.fetch_sub(1, Ordering::SeqCst);
+            }
+        })
+        .map_err(|e| Failure::Error(format!("Couldn't start a thread for the part: {}", e)))?;
+
+    let deadline = Instant::now() + budget;
+    loop {
+        match receiver.try_recv() {
+            Ok(result) => return result,
+            Err(TryRecvError::Disconnected) => {
+                return Err(Failure::Panic("the part's thread exited early".to_string()))
+            }
+            Err(TryRecvError::Empty) => {}
+        }
+        if Instant::now() >= deadline {
+            // Counted before the receiver is dropped, so before the part can see it's gone
+            OVERRUNNING.fetch_add(1, Ordering::SeqCst);
+            cancel.store(true, Ordering::Relaxed);
+            return Err(Failure::Timeout(budget));
+        }
+        // When waiting on a thread pool's thread, run the pool's other jobs in the meantime,
+        // as the part may be waiting on them (e.g. when it parallelises internally)
+        if rayon::yield_now() != Some(rayon::Yield::Executed) {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("Invalid time budget '{0}'. Should be a number of seconds, or end in ms, s or m, e.g. 500ms or 2m")]
+pub struct ParseBudgetError(String);
+
+/// Parses a time budget such as `30`, `30s`, `500ms` or `2m`.
+pub fn parse_budget(s: &str) -> Result<Duration, ParseBudgetError> {
+    let s = s.trim();
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(idx) => s.split_at(idx),
+        None => (s, "s"),
+    };
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| ParseBudgetError(s.into()))?;
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(ParseBudgetError(s.into())),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|budget| !budget.is_zero())
+        .ok_or(ParseBudgetError(s.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::progress::{Cancelled, Progress};
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_guarded_failures() {
+        assert_eq!(guarded(None, || Ok(42)), Ok(42));
+        assert_eq!(
+            guarded(None, || -> anyhow::Result<()> {
+                Err(anyhow!("bad input").context("Day 1 failed"))
+            }),
+            Err(Failure::Error("Day 1 failed: bad input".to_string()))
+        );
+        let budget = Some(Duration::from_secs(5));
+        assert_eq!(
+            guarded(budget, || -> anyhow::Result<()> { panic!("at the disco") }),
+            Err(Failure::Panic("at the disco".to_string()))
+        );
+        assert_eq!(guarded(budget, || Ok("in time")), Ok("in time"));
+    }
+
+    #[test]
+    fn test_guarded_timeout() {
+        let budget = Duration::from_millis(20);
+        let start = Instant::now();
+        let result = guarded(Some(budget), || {
+            thread::sleep(Duration::from_secs(2));
+            Ok(())
+        });
+        assert_eq!(result, Err(Failure::Timeout(budget)));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_guarded_timeout_cancels() {
+        let budget = Duration::from_millis(20);
+        let (stopped, stop) = mpsc::channel();
+        let result = guarded(Some(budget), move || -> anyhow::Result<()> {
+            let progress = Progress::new(1, "test guarded cancels");
+            while !progress.cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            stopped.send(()).unwrap();
+            Err(Cancelled.into())
+        });
+        assert_eq!(result, Err(Failure::Timeout(budget)));
+        // The part notices it was cancelled, rather than running forever
+        assert!(stop.recv_timeout(Duration::from_secs(5)).is_ok());
+    }
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!(parse_budget("30").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_budget("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_budget("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_budget("2m").unwrap(), Duration::from_secs(120));
+        assert!(parse_budget("0").is_err());
+        assert!(parse_budget("5h").is_err());
+        assert!(parse_budget("soon").is_err());
+    }
+}
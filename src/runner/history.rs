@@ -0,0 +1,160 @@
+use crate::runner::bench::BenchResult;
+use crate::utils::models::Year;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One `bench` of a day/part, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchRecord {
+    /// The commit that was benchmarked, suffixed with `-dirty` if there were local changes
+    pub commit: String,
+    pub input_hash: String,
+    pub year: Year,
+    pub day: u8,
+    pub part: u8,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub parse_ns: Vec<u64>,
+    pub solve_ns: Vec<u64>,
+}
+
+fn nanos(samples: &[Duration]) -> Vec<u64> {
+    samples.iter().map(|d| d.as_nanos() as u64).collect()
+}
+
+impl BenchRecord {
+    pub fn new(commit: &str, year: Year, result: &BenchResult) -> Self {
+        Self {
+            commit: commit.to_string(),
+            input_hash: result.input_hash.clone(),
+            year,
+            day: result.day.number(),
+            part: result.part.number(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            parse_ns: nanos(&result.parse_samples),
+            solve_ns: nanos(&result.solve_samples),
+        }
+    }
+
+    /// Whether the two records timed the same day/part on the same input.
+    pub fn is_comparable(&self, other: &BenchRecord) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.part == other.part
+            && self.input_hash == other.input_hash
+    }
+}
+
+/// The commit being benchmarked, from `git describe`, or `unknown` outside a git checkout.
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty", "--abbrev=12"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Every recorded benchmark, stored as one JSON record per line so runs can be appended.
+pub struct BenchHistory {
+    path: PathBuf,
+    pub records: Vec<BenchRecord>,
+}
+
+impl BenchHistory {
+    pub fn load(path: &Path) -> Result<Self> {
+        let records = match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .enumerate()
+                .map(|(i, line)| {
+                    serde_json::from_str(line).with_context(|| {
+                        format!("Invalid record on line {} of {}", i + 1, path.display())
+                    })
+                })
+                .collect::<Result<_>>()?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    /// The most recent earlier record of the same day/part on the same input.
+    pub fn previous(&self, record: &BenchRecord) -> Option<&BenchRecord> {
+        self.records.iter().rev().find(|r| r.is_comparable(record))
+    }
+
+    pub fn append(&mut self, records: &[BenchRecord]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        for record in records {
+            writeln!(file, "{}", serde_json::to_string(record)?)?;
+        }
+        self.records.extend_from_slice(records);
+        Ok(())
+    }
+}
+
+/// A record of benchmarking 2025 day 4 on the same input, for tests.
+#[cfg(test)]
+pub fn test_record(commit: &str, part: u8, solve_ns: Vec<u64>) -> BenchRecord {
+    BenchRecord {
+        commit: commit.to_string(),
+        input_hash: "0123456789abcdef".to_string(),
+        year: Year::new(2025),
+        day: 4,
+        part,
+        timestamp: 0,
+        parse_ns: vec![1000, 1000],
+        solve_ns,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, input_hash: &str) -> BenchRecord {
+        BenchRecord {
+            input_hash: input_hash.to_string(),
+            ..test_record(commit, 1, vec![100, 110])
+        }
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc_history_{}", std::process::id()))
+            .join("bench_history.jsonl");
+        let mut history = BenchHistory::load(&path).unwrap();
+        history
+            .append(&[record("abc", "in1"), record("def", "in2")])
+            .unwrap();
+        history.append(&[record("ghi", "in1")]).unwrap();
+
+        let history = BenchHistory::load(&path).unwrap();
+        assert_eq!(history.records.len(), 3);
+        let previous = history.previous(&record("jkl", "in1")).unwrap();
+        assert_eq!(previous.commit, "ghi");
+        assert!(history.previous(&record("jkl", "in3")).is_none());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
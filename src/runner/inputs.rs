@@ -0,0 +1,86 @@
+use crate::utils::load::{find_input, read_input, PuzzleInput};
+use crate::utils::models::{
+    CalendarDay, Day, DaySelection, InputSource, Part, PartSelection, Year,
+};
+use crate::utils::registry;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// A day, with the input for each of its selected parts.
+pub type DayInputs = (Day, Vec<(Part, PuzzleInput)>);
+
+/// Which days and parts to run, and which input to run them on.
+#[derive(Debug, Clone)]
+pub struct InputSelection {
+    /// Every registered day of the year when not set
+    pub days: Option<DaySelection>,
+    pub parts: PartSelection,
+    /// Read instead of the days' own inputs. Only allowed for a single day
+    pub input: Option<InputSource>,
+    /// Run against the puzzle's example N instead of the real input
+    pub example: Option<u8>,
+}
+
+impl InputSelection {
+    /// The selected days of `year`.
+    pub fn days(&self, year: Year) -> Result<Vec<Day>> {
+        days_or_all(year, self.days.as_ref())
+    }
+
+    /// Reads the input for each selected day/part.
+    pub fn load(&self, year: Year) -> Result<Vec<DayInputs>> {
+        load_inputs(
+            year,
+            self.days(year)?,
+            &self.parts.parts(),
+            self.input.as_ref(),
+            self.example,
+        )
+    }
+}
+
+/// The days picked by `selection`, or every registered day of `year`.
+pub fn days_or_all(year: Year, selection: Option<&DaySelection>) -> Result<Vec<Day>> {
+    match selection {
+        Some(selection) => Ok(selection.resolve(year)?),
+        None => Ok(registry::days(year).iter().map(|r| r.day).collect()),
+    }
+}
+
+/// Reads the input for each of `parts` of each of `days`, from `input` if given, and
+/// otherwise from the days' input (or example) files.
+pub fn load_inputs(
+    year: Year,
+    days: Vec<Day>,
+    parts: &[Part],
+    input: Option<&InputSource>,
+    example: Option<u8>,
+) -> Result<Vec<DayInputs>> {
+    if input.is_some() && days.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+    let mut loaded = vec![];
+    for day in days {
+        let _span = tracing::info_span!("load", day = day.number()).entered();
+        let mut inputs: Vec<(Part, PuzzleInput)> = vec![];
+        for &part in parts {
+            let source = match input {
+                Some(input) => input.clone(),
+                None => find_input(Path::new(""), CalendarDay::new(year, day), part, example)?,
+            };
+            // Parts sharing an input (including stdin) only read it once
+            let previous = inputs.iter().find(|(_, input)| input.source == source);
+            let input = match previous {
+                Some((_, previous)) => previous.clone(),
+                None => {
+                    let contents = read_input(&source)
+                        .with_context(|| format!("Couldn't read input from {}", source))?;
+                    PuzzleInput::new(source, contents)
+                }
+            };
+            inputs.push((part, input));
+        }
+        loaded.push((day, inputs));
+    }
+    Ok(loaded)
+}
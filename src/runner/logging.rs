@@ -0,0 +1,68 @@
+use crate::runner::progress;
+use anyhow::Result;
+use std::io::IsTerminal;
+use std::path::Path;
+use tracing::level_filters::LevelFilter;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+/// The log level for a verbosity, where 0 is the default (`info`), each `-v` adds one and
+/// each `-q` takes one away.
+pub fn level(verbosity: i8) -> LevelFilter {
+    match verbosity {
+        i8::MIN..=-3 => LevelFilter::OFF,
+        -2 => LevelFilter::ERROR,
+        -1 => LevelFilter::WARN,
+        0 => LevelFilter::INFO,
+        1 => LevelFilter::DEBUG,
+        2..=i8::MAX => LevelFilter::TRACE,
+    }
+}
+
+/// Sends logs to stderr, filtered by `RUST_LOG` if it's set or by `verbosity` otherwise,
+/// and records spans to `chrome_trace` if given. The returned guard writes out the trace
+/// when it's dropped.
+pub fn init(verbosity: i8, chrome_trace: Option<&Path>) -> Result<Option<FlushGuard>> {
+    let filter = EnvFilter::builder()
+        .with_default_directive(level(verbosity).into())
+        .from_env()?;
+    let logs = fmt::layer()
+        .with_writer(progress::log_writer)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_filter(filter);
+
+    // The trace is for profiling, so it keeps the day/part/phase spans even when logs are quiet
+    let (chrome, guard) = match chrome_trace {
+        Some(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .file(path)
+                .include_args(true)
+                .build();
+            let filter = level(verbosity).max(LevelFilter::INFO);
+            (Some(layer.with_filter(filter)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(logs)
+        .with(chrome)
+        .init();
+    Ok(guard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), LevelFilter::INFO);
+        assert_eq!(level(-1), LevelFilter::WARN);
+        assert_eq!(level(2), LevelFilter::TRACE);
+        assert_eq!(level(5), LevelFilter::TRACE);
+        assert_eq!(level(-5), LevelFilter::OFF);
+    }
+}
@@ -0,0 +1,264 @@
+pub mod alloc;
+pub mod bench;
+pub mod client;
+pub mod compare;
+pub mod fetch;
+pub mod guard;
+pub mod history;
+pub mod inputs;
+pub mod logging;
+pub mod output;
+pub mod progress;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod table;
+pub mod tui;
+pub mod verify;
+pub mod visualize;
+pub mod watch;
+
+use crate::runner::alloc::AllocStats;
+use crate::runner::guard::{guarded, Failure};
+use crate::runner::inputs::{DayInputs, InputSelection};
+use crate::runner::output::OutputFormat;
+use crate::runner::progress::ProgressBar;
+use crate::runner::visualize::{write_visualizations, FrameFormat};
+use crate::utils::advent_day::{ParsedInput, Solver};
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::load::PuzzleInput;
+use crate::utils::models::{CalendarDay, Day, Part, Year};
+use crate::utils::registry;
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The outcome of running a single day/part.
+pub struct PartRun {
+    pub day: Day,
+    pub part: Part,
+    /// The answer, or why there isn't one
+    pub answer: Result<Answer, Failure>,
+    /// Where the input was read from
+    pub input: String,
+    pub input_hash: String,
+    /// Zero when the input was already parsed for an earlier part
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// What parsing (if this part did it) and solving allocated, when allocations are counted
+    pub allocations: Option<AllocStats>,
+}
+
+impl PartRun {
+    /// The answer, or a one line summary of why there isn't one.
+    pub fn answer_text(&self) -> String {
+        match &self.answer {
+            Ok(answer) => answer.to_string(),
+            Err(failure) => failure.summary(),
+        }
+    }
+
+    fn failed(day: Day, part: Part, input: &PuzzleInput, failure: Failure) -> Self {
+        Self {
+            day,
+            part,
+            answer: Err(failure),
+            input: input.source.to_string(),
+            input_hash: input.hash.clone(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            allocations: None,
+        }
+    }
+}
+
+/// A day's parsed input, along with what parsing it cost.
+pub struct Parsed {
+    pub input: ParsedInput,
+    pub time: Duration,
+    pub allocations: Option<AllocStats>,
+}
+
+pub fn parse_input(solver: &dyn Solver, day: Day, input: &PuzzleInput) -> Result<Parsed> {
+    let _span = tracing::info_span!("parse").entered();
+    let start = Instant::now();
+    let (parsed, allocations) = alloc::measure(|| solver.parse(&input.contents));
+    // Days only see the contents, so point their diagnostics at the file here
+    let parsed = parsed
+        .map_err(|e| match e.downcast::<Diagnostic>() {
+            Ok(diagnostic) => diagnostic.in_file(&input.source).into(),
+            Err(e) => e,
+        })
+        .with_context(|| format!("Day {} couldn't parse {}", day.number(), input.source))?;
+    Ok(Parsed {
+        input: parsed,
+        time: start.elapsed(),
+        allocations,
+    })
+}
+
+/// Solves a part from an already parsed input. The parse is only included in the part's
+/// costs if it was parsed for this part, rather than reused from an earlier one.
+pub fn solve_part(
+    solver: &dyn Solver,
+    day: Day,
+    part: Part,
+    input: &PuzzleInput,
+    parsed: &Parsed,
+    parsed_for_part: bool,
+) -> Result<PartRun> {
+    let _span = tracing::info_span!("solve").entered();
+    let start = Instant::now();
+    let (answer, allocations) = alloc::measure(|| solver.solve(part, &parsed.input));
+    let solve_time = start.elapsed();
+    let answer =
+        answer.with_context(|| format!("Day {} Part {} failed", day.number(), part.number()))?;
+    let (parse_time, allocations) = match (parsed_for_part, parsed.allocations, allocations) {
+        (true, Some(parse), Some(solve)) => (parsed.time, Some(parse.then(solve))),
+        (true, _, allocations) => (parsed.time, allocations),
+        (false, _, allocations) => (Duration::ZERO, allocations),
+    };
+    Ok(PartRun {
+        day,
+        part,
+        answer: Ok(answer),
+        input: input.source.to_string(),
+        input_hash: input.hash.clone(),
+        parse_time,
+        solve_time,
+        allocations,
+    })
+}
+
+pub fn run_part(solver: &dyn Solver, day: Day, part: Part, input: &PuzzleInput) -> Result<PartRun> {
+    let _day = tracing::info_span!("day", day = day.number()).entered();
+    let _part = tracing::info_span!("part", part = part.number()).entered();
+    let parsed = parse_input(solver, day, input)?;
+    solve_part(solver, day, part, input, &parsed, true)
+}
+
+/// Runs each part of a day on its input, parsing an input shared by several parts only once.
+///
+/// Errors and panics are caught, and each phase is given up on after `budget`, so a part
+/// that fails is returned with the failure in place of its answer rather than stopping the
+/// rest of the run.
+pub fn run_day(
+    solver: &'static dyn Solver,
+    day: Day,
+    inputs: &[(Part, PuzzleInput)],
+    budget: Option<Duration>,
+) -> Vec<PartRun> {
+    // A thread waiting on a part runs other days in the meantime, so days are always roots
+    // rather than nesting under whichever part that thread was waiting on
+    let _span = tracing::info_span!(parent: None, "day", day = day.number()).entered();
+    let mut runs = vec![];
+    let mut previous: Option<(&PuzzleInput, Result<Arc<Parsed>, Failure>)> = None;
+    for (part, input) in inputs {
+        let _span = tracing::info_span!("part", part = part.number()).entered();
+        let part = *part;
+        let mut parse_time = Duration::ZERO;
+        let parsed_for_part = match &previous {
+            Some((parsed_from, _)) if parsed_from.source == input.source => false,
+            _ => {
+                let owned = input.clone();
+                let start = Instant::now();
+                let parsed = guarded(budget, move || parse_input(solver, day, &owned));
+                parse_time = start.elapsed();
+                previous = Some((input, parsed.map(Arc::new)));
+                true
+            }
+        };
+        let parsed = match &previous {
+            Some((_, Ok(parsed))) => Arc::clone(parsed),
+            Some((_, Err(failure))) => {
+                runs.push(PartRun {
+                    parse_time,
+                    ..PartRun::failed(day, part, input, failure.clone())
+                });
+                continue;
+            }
+            None => unreachable!("input was parsed"),
+        };
+        let owned = input.clone();
+        let for_solve = Arc::clone(&parsed);
+        let start = Instant::now();
+        let run = guarded(budget, move || {
+            solve_part(solver, day, part, &owned, &for_solve, parsed_for_part)
+        });
+        runs.push(run.unwrap_or_else(|failure| PartRun {
+            parse_time: if parsed_for_part {
+                parsed.time
+            } else {
+                Duration::ZERO
+            },
+            solve_time: start.elapsed(),
+            ..PartRun::failed(day, part, input, failure)
+        }));
+    }
+    runs
+}
+
+/// Runs every loaded day of `year`, with a progress bar while they run.
+pub fn run_days(
+    year: Year,
+    inputs: &[DayInputs],
+    budget: Option<Duration>,
+) -> Result<Vec<PartRun>> {
+    let _progress = ProgressBar::show();
+    let run = |(day, inputs): &DayInputs| {
+        let solver = registry::solver(CalendarDay::new(year, *day))?;
+        Ok(run_day(solver, *day, inputs, budget))
+    };
+    // Days run concurrently, but the results are collected in day order. Allocation counters
+    // are shared by every thread, so days run one at a time when allocations are counted
+    let runs: Vec<Vec<PartRun>> = if alloc::ENABLED {
+        inputs.iter().map(run).collect::<Result<_>>()?
+    } else {
+        inputs.par_iter().map(run).collect::<Result<_>>()?
+    };
+    Ok(runs.into_iter().flatten().collect_vec())
+}
+
+/// Prints the full reason for each failed part, which the summary only has room for the
+/// first line of, returning how many failed.
+pub fn report_failures<'a>(runs: impl IntoIterator<Item = &'a PartRun>) -> usize {
+    let failures = runs
+        .into_iter()
+        .filter_map(|run| Some((run, run.answer.as_ref().err()?)))
+        .collect_vec();
+    for (run, failure) in &failures {
+        eprintln!(
+            "Day {} Part {} {}",
+            run.day.number(),
+            run.part.number(),
+            failure
+        );
+    }
+    failures.len()
+}
+
+/// Runs the selected days and prints their results in `format`, then writes their frames
+/// into the `visualize` directory if given. Fails if any part did.
+pub fn run_selection(
+    year: Year,
+    selection: &InputSelection,
+    format: OutputFormat,
+    budget: Option<Duration>,
+    visualize: Option<(&Path, FrameFormat)>,
+) -> Result<()> {
+    let inputs = selection.load(year)?;
+    let runs = run_days(year, &inputs, budget)?;
+    println!("{}", output::render(year, &runs, format)?);
+    if let Some((dir, frame_format)) = visualize {
+        write_visualizations(year, &inputs, dir, frame_format)?;
+    }
+    let failures = report_failures(&runs);
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
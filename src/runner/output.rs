@@ -0,0 +1,163 @@
+use crate::runner::alloc::AllocStats;
+use crate::runner::{table, PartRun};
+use crate::utils::models::Year;
+use anyhow::Result;
+use itertools::Itertools;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// The answer for a single run, or a summary table for several
+    Text,
+    /// A JSON array with one record per run
+    Json,
+    /// A CSV header followed by one row per run
+    Csv,
+}
+
+/// A machine readable record of a single run.
+#[derive(Debug, Serialize)]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Missing if the part failed
+    pub answer: Option<String>,
+    /// Why the part failed, if it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub input: String,
+    pub input_hash: String,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    /// Only present when built with the `count-allocations` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+}
+
+impl RunRecord {
+    pub fn new(year: Year, run: &PartRun) -> Self {
+        Self {
+            year: year.number(),
+            day: run.day.number(),
+            part: run.part.number(),
+            answer: run.answer.as_ref().ok().map(ToString::to_string),
+            error: run.answer.as_ref().err().map(ToString::to_string),
+            input: run.input.clone(),
+            input_hash: run.input_hash.clone(),
+            parse_ns: run.parse_time.as_nanos(),
+            solve_ns: run.solve_time.as_nanos(),
+            allocations: run.allocations,
+        }
+    }
+}
+
+const CSV_HEADERS: [&str; 9] = [
+    "year",
+    "day",
+    "part",
+    "answer",
+    "input",
+    "input_hash",
+    "parse_ns",
+    "solve_ns",
+    "error",
+];
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_row(record: &RunRecord) -> String {
+    [
+        record.year.to_string(),
+        record.day.to_string(),
+        record.part.to_string(),
+        csv_field(record.answer.as_deref().unwrap_or_default()),
+        csv_field(&record.input),
+        record.input_hash.clone(),
+        record.parse_ns.to_string(),
+        record.solve_ns.to_string(),
+        csv_field(record.error.as_deref().unwrap_or_default()),
+    ]
+    .join(",")
+}
+
+/// Renders the runs of `year`'s days in `format`.
+pub fn render(year: Year, runs: &[PartRun], format: OutputFormat) -> Result<String> {
+    let records = || runs.iter().map(|run| RunRecord::new(year, run));
+    Ok(match format {
+        OutputFormat::Text => match runs {
+            [run] => run.answer_text(),
+            _ => table::render(runs),
+        },
+        OutputFormat::Json => serde_json::to_string_pretty(&records().collect_vec())?,
+        OutputFormat::Csv => [CSV_HEADERS.join(",")]
+            .into_iter()
+            .chain(records().map(|record| csv_row(&record)))
+            .join("\n"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::guard::Failure;
+    use crate::utils::answer::Answer;
+    use crate::utils::models::{Day, Part};
+    use std::time::Duration;
+
+    const YEAR: Year = Year::new(2025);
+
+    fn runs() -> Vec<PartRun> {
+        vec![PartRun {
+            day: Day::new(6),
+            part: Part::Part1,
+            answer: Ok(Answer::Text("a,\"b\"".to_string())),
+            input: "inputs/2025/day_6/part1.txt".to_string(),
+            input_hash: "abc123".to_string(),
+            parse_time: Duration::from_nanos(1500),
+            solve_time: Duration::from_micros(20),
+            allocations: None,
+        }]
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render(YEAR, &runs(), OutputFormat::Csv).unwrap();
+        assert_eq!(
+            csv,
+            "year,day,part,answer,input,input_hash,parse_ns,solve_ns,error\n\
+             2025,6,1,\"a,\"\"b\"\"\",inputs/2025/day_6/part1.txt,abc123,1500,20000,"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = render(YEAR, &runs(), OutputFormat::Json).unwrap();
+        let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(records[0]["year"], 2025);
+        assert_eq!(records[0]["day"], 6);
+        assert_eq!(records[0]["answer"], "a,\"b\"");
+        assert_eq!(records[0]["solve_ns"], 20000);
+        assert!(records[0].get("error").is_none());
+    }
+
+    #[test]
+    fn test_render_failure() {
+        let mut runs = runs();
+        runs[0].answer = Err(Failure::Timeout(Duration::from_secs(2)));
+        let json = render(YEAR, &runs, OutputFormat::Json).unwrap();
+        let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(records[0]["answer"].is_null());
+        assert_eq!(records[0]["error"], "timed out after 2.00s");
+        let csv = render(YEAR, &runs, OutputFormat::Csv).unwrap();
+        assert!(
+            csv.ends_with(",,inputs/2025/day_6/part1.txt,abc123,1500,20000,timed out after 2.00s")
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::utils::progress::{active, format_count, Snapshot};
+use itertools::Itertools;
+use std::io::{IsTerminal, Write};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often the bar is redrawn.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Jobs that finish quicker than this are never shown, so fast parts don't flicker a bar.
+const SHOW_AFTER: Duration = Duration::from_millis(250);
+
+const BAR_WIDTH: usize = 20;
+
+/// Whether the bar is on screen. Held while drawing or logging, so the two don't interleave.
+static DRAWN: Mutex<bool> = Mutex::new(false);
+
+fn drawn() -> MutexGuard<'static, bool> {
+    DRAWN.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn clear(drawn: &mut bool) {
+    if *drawn {
+        eprint!("\r\x1b[2K");
+        *drawn = false;
+    }
+}
+
+/// Writes a log line to stderr, clearing the bar first. It's redrawn underneath at the next
+/// refresh.
+pub struct LogWriter {
+    /// Keeps the bar from being drawn until the line is written
+    _drawn: MutexGuard<'static, bool>,
+}
+
+/// For `tracing_subscriber::fmt::Layer::with_writer`.
+pub fn log_writer() -> LogWriter {
+    let mut drawn = drawn();
+    clear(&mut drawn);
+    LogWriter { _drawn: drawn }
+}
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        std::io::stderr().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        std::io::stderr().flush()
+    }
+}
+
+/// Draws the progress of solvers' jobs on one line of stderr, until dropped.
+pub struct ProgressBar {
+    stop: Option<Sender<()>>,
+    drawer: Option<JoinHandle<()>>,
+}
+
+impl ProgressBar {
+    /// Starts drawing, unless stderr isn't a terminal (e.g. it's piped to a file), where the
+    /// redraws would just be noise.
+    pub fn show() -> Option<Self> {
+        if !std::io::stderr().is_terminal() {
+            return None;
+        }
+        let (stop, stopped) = mpsc::channel();
+        let drawer = thread::Builder::new()
+            .name("progress".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(REFRESH_INTERVAL) {
+                    let line = render(&active());
+                    let mut drawn = drawn();
+                    clear(&mut drawn);
+                    if !line.is_empty() {
+                        eprint!("{}", line);
+                        let _ = std::io::stderr().flush();
+                        *drawn = true;
+                    }
+                }
+                clear(&mut drawn());
+            })
+            .ok()?;
+        Some(Self {
+            stop: Some(stop),
+            drawer: Some(drawer),
+        })
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        // Clear the line before anything else is printed
+        drop(self.stop.take());
+        if let Some(drawer) = self.drawer.take() {
+            let _ = drawer.join();
+        }
+    }
+}
+
+fn render_job(job: &Snapshot) -> String {
+    let filled = (job.fraction() * BAR_WIDTH as f64).round() as usize;
+    format!(
+        "[{}{}] {:>3.0}% {} of {} {}",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        job.fraction() * 100.0,
+        format_count(job.done),
+        format_count(job.total),
+        job.unit
+    )
+}
+
+/// One line for every job that's been running long enough to be worth showing.
+pub fn render(jobs: &[Snapshot]) -> String {
+    jobs.iter()
+        .filter(|job| job.elapsed >= SHOW_AFTER)
+        .map(render_job)
+        .join("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(done: u64, elapsed: Duration) -> Snapshot {
+        Snapshot {
+            unit: "rectangles checked".to_string(),
+            done,
+            total: 12_000_000,
+            elapsed,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let slow = job(1_200_000, Duration::from_secs(3));
+        let quick = job(5, Duration::from_millis(10));
+        assert_eq!(
+            render(&[slow, quick]),
+            "[##------------------]  10% 1.2M of 12M rectangles checked"
+        );
+        assert_eq!(render(&[]), "");
+    }
+}
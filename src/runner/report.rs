@@ -0,0 +1,185 @@
+use crate::runner::compare::{compare, Verdict};
+use crate::runner::history::BenchRecord;
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::path::Path;
+use std::time::Duration;
+
+const HEADERS: [&str; 6] = ["Commit", "Input", "Runs", "Parse", "Solve", "Verdict"];
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// Picks the format from the report's file extension.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("md") | Some("markdown") => Ok(ReportFormat::Markdown),
+            Some("html") | Some("htm") => Ok(ReportFormat::Html),
+            _ => bail!(
+                "Can't tell the report format of {}: use a .md or .html file",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// The recorded runs of one day/part, in the order they were recorded.
+struct Section {
+    title: String,
+    trend: String,
+    rows: Vec<Vec<String>>,
+}
+
+fn mean(samples: &[u64]) -> Duration {
+    Duration::from_nanos(samples.iter().sum::<u64>() / samples.len().max(1) as u64)
+}
+
+/// A one line chart of mean solve times, scaled between the fastest and slowest run.
+fn sparkline(means: &[Duration]) -> String {
+    let (Some(min), Some(max)) = (means.iter().min(), means.iter().max()) else {
+        return String::new();
+    };
+    let range = (*max - *min).as_secs_f64();
+    means
+        .iter()
+        .map(|mean| {
+            let scaled = if range == 0.0 {
+                0.0
+            } else {
+                (*mean - *min).as_secs_f64() / range
+            };
+            SPARKS[(scaled * (SPARKS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+fn sections(records: &[BenchRecord]) -> Vec<Section> {
+    records
+        .iter()
+        .into_group_map_by(|record| (record.year, record.day, record.part))
+        .into_iter()
+        .sorted_by_key(|(key, _)| *key)
+        .map(|((year, day, part), runs)| {
+            let rows = runs
+                .iter()
+                .enumerate()
+                .map(|(i, run)| {
+                    // Only runs on the same input are comparable
+                    let previous = runs[..i].iter().rev().find(|r| r.is_comparable(run));
+                    let verdict = previous
+                        .map(|previous| {
+                            let comparisons = compare(previous, run);
+                            comparisons
+                                .iter()
+                                .find(|c| c.verdict != Verdict::Unchanged)
+                                .map_or("-".to_string(), |c| format!("{} {}", c.phase, c.verdict))
+                        })
+                        .unwrap_or_default();
+                    vec![
+                        run.commit.clone(),
+                        run.input_hash.chars().take(8).collect(),
+                        run.solve_ns.len().to_string(),
+                        format!("{:.2?}", mean(&run.parse_ns)),
+                        format!("{:.2?}", mean(&run.solve_ns)),
+                        verdict,
+                    ]
+                })
+                .collect();
+            let means = runs.iter().map(|run| mean(&run.solve_ns)).collect_vec();
+            Section {
+                title: format!("{} Day {} Part {}", year, day, part),
+                trend: sparkline(&means),
+                rows,
+            }
+        })
+        .collect()
+}
+
+fn render_markdown(sections: &[Section]) -> String {
+    let mut report = "# Benchmark history\n".to_string();
+    for section in sections {
+        report.push_str(&format!(
+            "\n## {}\n\nSolve time trend: {}\n\n| {} |\n|{}\n",
+            section.title,
+            section.trend,
+            HEADERS.join(" | "),
+            "---|".repeat(HEADERS.len())
+        ));
+        for row in &section.rows {
+            report.push_str(&format!("| {} |\n", row.join(" | ")));
+        }
+    }
+    report
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn render_html(sections: &[Section]) -> String {
+    let cells = |tag: &str, cells: &[String]| {
+        cells
+            .iter()
+            .map(|cell| format!("<{tag}>{}</{tag}>", escape_html(cell)))
+            .join("")
+    };
+    let headers = HEADERS.map(String::from);
+    let mut report = "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>Benchmark history</title></head>\n<body>\n<h1>Benchmark history</h1>\n".to_string();
+    for section in sections {
+        report.push_str(&format!(
+            "<h2>{}</h2>\n<p>Solve time trend: {}</p>\n<table>\n<tr>{}</tr>\n",
+            escape_html(&section.title),
+            section.trend,
+            cells("th", &headers)
+        ));
+        for row in &section.rows {
+            report.push_str(&format!("<tr>{}</tr>\n", cells("td", row)));
+        }
+        report.push_str("</table>\n");
+    }
+    report.push_str("</body>\n</html>\n");
+    report
+}
+
+/// Renders every recorded run, grouped by day/part, with a trend line for each.
+pub fn render(records: &[BenchRecord], format: ReportFormat) -> String {
+    let sections = sections(records);
+    match format {
+        ReportFormat::Markdown => render_markdown(&sections),
+        ReportFormat::Html => render_html(&sections),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::history::test_record as record;
+
+    #[test]
+    fn test_render_markdown() {
+        let records = [
+            record("abc", 1, vec![1000, 1010, 990]),
+            record("abc", 2, vec![5000, 5000]),
+            record("def", 1, vec![2000, 2010, 1990]),
+        ];
+        let report = render(&records, ReportFormat::Markdown);
+        assert!(report.contains("## 2025 Day 4 Part 1\n\nSolve time trend: ▁█\n"));
+        assert!(report.contains("| def | 01234567 | 3 | 1.00µs | 2.00µs | solve SLOWER |\n"));
+        assert!(report.contains("## 2025 Day 4 Part 2"));
+    }
+
+    #[test]
+    fn test_report_format_from_path() {
+        let format = |path: &str| ReportFormat::from_path(Path::new(path));
+        assert_eq!(format("report.md").unwrap(), ReportFormat::Markdown);
+        assert_eq!(format("out/report.html").unwrap(), ReportFormat::Html);
+        assert!(format("report.txt").is_err());
+    }
+}
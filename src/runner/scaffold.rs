@@ -0,0 +1,240 @@
+use crate::utils::load::day_dir;
+use crate::utils::models::{CalendarDay, Day};
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MOD_TEMPLATE: &str = "pub mod errors;
+pub mod run;
+";
+
+const ERRORS_TEMPLATE: &str = r#"use thiserror::Error;
+
+#[derive(Debug, Error, Clone)]
+#[error("Invalid input for day {{number}}.")]
+pub struct ParseInputError;
+"#;
+
+const RUN_TEMPLATE: &str = r#"use crate::days::y{{year}}::day_{{number}}::errors::ParseInputError;
+use crate::utils::advent_day::AdventDay;
+use crate::utils::answer::Answer;
+use crate::utils::diagnostic::Diagnostic;
+use crate::utils::load::load_from_str;
+use crate::utils::registry::RegisteredDay;
+use anyhow::{anyhow, Result};
+
+pub struct {{struct}};
+
+inventory::submit! {
+    RegisteredDay::new({{year}}, {{number}}, "{{name}}", &{{struct}})
+}
+
+impl AdventDay for {{struct}} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        // Report problems with Diagnostic::new(input, offending_slice, error) so they point
+        // at the right line and column
+        let lines = load_from_str(input);
+        if lines.is_empty() {
+            return Err(Diagnostic::at_end(input, ParseInputError).into());
+        }
+        Ok(lines)
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<Answer> {
+        tracing::info!("Day {{number}}: Part 1");
+        Err(anyhow!("Part 1 isn't solved yet ({} lines of input)", lines.len()))
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<Answer> {
+        tracing::info!("Day {{number}}: Part 2");
+        Err(anyhow!("Part 2 isn't solved yet ({} lines of input)", lines.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore = "add the puzzle example to examples/{{year}}/day_{{number}}/, then use example_tests!"]
+    fn test_part1_example() {
+        crate::utils::examples::check_examples(
+            &super::{{struct}},
+            crate::utils::models::CalendarDay::new(
+                crate::utils::models::Year::new({{year}}),
+                crate::utils::models::Day::new({{number}}),
+            ),
+            "part_1",
+        );
+    }
+}
+"#;
+
+const EXAMPLE_ANSWERS_TEMPLATE: &str = "# Expected answers for example1.txt, e.g.:
+# part_1 = 1234
+# part_2 = 5678
+";
+
+const NUMBER_WORDS: [&str; 25] = [
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+    "Twenty",
+    "TwentyOne",
+    "TwentyTwo",
+    "TwentyThree",
+    "TwentyFour",
+    "TwentyFive",
+];
+
+/// The solver struct name for a day, following the existing `DayOne`, `DayTwo`, ... naming.
+pub fn struct_name(day: Day) -> Result<String> {
+    match NUMBER_WORDS.get((day.number() as usize).wrapping_sub(1)) {
+        Some(word) => Ok(format!("Day{}", word)),
+        None => bail!("Day must be between 1 and 25, got {}", day),
+    }
+}
+
+fn render(template: &str, day: CalendarDay, name: &str) -> Result<String> {
+    Ok(template
+        .replace("{{year}}", &day.year.to_string())
+        .replace("{{number}}", &day.day.to_string())
+        .replace("{{struct}}", &struct_name(day.day)?)
+        .replace("{{name}}", &name.replace('"', "\\\"")))
+}
+
+/// Adds `pub mod <name>;` to the `mod.rs` at `path`, creating it if needed, keeping the
+/// declarations ordered by the number at the end of each name.
+fn declare_module(path: &Path, name: &str) -> Result<()> {
+    let declarations = match fs::read_to_string(path) {
+        Ok(declarations) => declarations,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut lines: Vec<String> = declarations.lines().map(String::from).collect();
+    lines.push(format!("pub mod {};", name));
+    lines.sort_by_key(|line| {
+        line.trim_end_matches(';')
+            .rsplit(|c: char| !c.is_ascii_digit())
+            .next()
+            .and_then(|number| number.parse::<u16>().ok())
+            .unwrap_or(u16::MAX)
+    });
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// Creates `src/days/yYYYY/day_N/` with a skeleton solver and declares it, along with the
+/// year's module if it's the year's first day. Also creates `inputs/YYYY/day_N/` and an empty
+/// example in `examples/YYYY/day_N/`. Returns the paths that were created.
+pub fn new_day(root: &Path, day: CalendarDay, name: &str) -> Result<Vec<PathBuf>> {
+    struct_name(day.day)?;
+    let year_module = format!("y{}", day.year);
+    let year_dir = root.join("src/days").join(&year_module);
+    let module_dir = year_dir.join(format!("day_{}", day.day));
+    if module_dir.exists() {
+        bail!("{} already exists", module_dir.display());
+    }
+
+    let mut created = vec![];
+    if !year_dir.exists() {
+        declare_module(&root.join("src/days/mod.rs"), &year_module)?;
+        created.push(year_dir.join("mod.rs"));
+    }
+    fs::create_dir_all(&module_dir)?;
+    for (file, template) in [
+        ("mod.rs", MOD_TEMPLATE),
+        ("errors.rs", ERRORS_TEMPLATE),
+        ("run.rs", RUN_TEMPLATE),
+    ] {
+        let path = module_dir.join(file);
+        fs::write(&path, render(template, day, name)?)?;
+        created.push(path);
+    }
+    declare_module(&year_dir.join("mod.rs"), &format!("day_{}", day.day))?;
+
+    let examples_dir = root.join("examples").join(day_dir(day));
+    fs::create_dir_all(&examples_dir)?;
+    for (file, contents) in [
+        ("example1.txt", ""),
+        ("example1.toml", EXAMPLE_ANSWERS_TEMPLATE),
+    ] {
+        let path = examples_dir.join(file);
+        if !path.exists() {
+            fs::write(&path, contents)?;
+            created.push(path);
+        }
+    }
+
+    let inputs_dir = root.join("inputs").join(day_dir(day));
+    fs::create_dir_all(&inputs_dir)?;
+    created.push(inputs_dir);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::models::Year;
+
+    #[test]
+    fn test_struct_name() {
+        assert_eq!(struct_name(Day::new(3)).unwrap(), "DayThree");
+        assert_eq!(struct_name(Day::new(12)).unwrap(), "DayTwelve");
+        assert!(struct_name(Day::new(0)).is_err());
+        assert!(struct_name(Day::new(26)).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days/y2025")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), "pub mod y2025;\n").unwrap();
+        fs::write(
+            root.join("src/days/y2025/mod.rs"),
+            "pub mod day_1;\npub mod day_11;\n",
+        )
+        .unwrap();
+        let day = |year, day| CalendarDay::new(Year::new(year), Day::new(day));
+
+        new_day(&root, day(2025, 10), "Factory").unwrap();
+
+        let days_mod = fs::read_to_string(root.join("src/days/y2025/mod.rs")).unwrap();
+        assert_eq!(
+            days_mod,
+            "pub mod day_1;\npub mod day_10;\npub mod day_11;\n"
+        );
+        let run = fs::read_to_string(root.join("src/days/y2025/day_10/run.rs")).unwrap();
+        assert!(run.contains("RegisteredDay::new(2025, 10, \"Factory\", &DayTen)"));
+        assert!(run.contains("crate::days::y2025::day_10::errors"));
+        assert!(!run.contains("{{"));
+        assert!(root.join("inputs/2025/day_10").is_dir());
+        assert!(root.join("examples/2025/day_10/example1.toml").is_file());
+        assert!(new_day(&root, day(2025, 10), "Factory").is_err());
+
+        // The first day of a new year declares the year too
+        new_day(&root, day(2024, 1), "Historian Hysteria").unwrap();
+        let years_mod = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert_eq!(years_mod, "pub mod y2024;\npub mod y2025;\n");
+        let days_mod = fs::read_to_string(root.join("src/days/y2024/mod.rs")).unwrap();
+        assert_eq!(days_mod, "pub mod day_1;\n");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,283 @@
+use crate::runner::client::AocClient;
+use crate::runner::inputs::load_inputs;
+use crate::runner::progress::ProgressBar;
+use crate::runner::run_part;
+use crate::utils::answer::Answer;
+use crate::utils::models::{CalendarDay, InputSource, Part, Year};
+use crate::utils::registry;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How the puzzle site responded to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint about which way
+    Wrong,
+    RateLimited,
+    AlreadySolved,
+    /// A response that couldn't be recognised
+    Unknown,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited => write!(f, "rate limited, try again later"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+pub fn parse_response(body: &str) -> Outcome {
+    if body.contains("That's the right answer") {
+        Outcome::Correct
+    } else if body.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if body.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if body.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if body.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if body.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: Year,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, kept so that answers which are already known
+/// to be wrong are never sent.
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: &Path) -> Result<Self> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.submissions)?)?;
+        Ok(())
+    }
+
+    /// Returns why `answer` shouldn't be submitted, if anything already recorded rules it out.
+    pub fn refusal(&self, day: CalendarDay, part: Part, answer: &str) -> Option<String> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == day.year && s.day == day.day.number() && s.part == part.number());
+        let numeric = answer.parse::<i128>().ok();
+        for submission in previous {
+            let bound = submission.answer.parse::<i128>().ok();
+            match (submission.outcome, numeric, bound) {
+                (Outcome::Correct, _, _) => {
+                    return Some(format!("already solved with {}", submission.answer))
+                }
+                (Outcome::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return Some(format!("{} was already too high", submission.answer))
+                }
+                (Outcome::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return Some(format!("{} was already too low", submission.answer))
+                }
+                (Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong, _, _)
+                    if submission.answer == answer =>
+                {
+                    return Some(format!("{} was already wrong", answer))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, day: CalendarDay, part: Part, answer: &str, outcome: Outcome) {
+        self.submissions.push(Submission {
+            year: day.year,
+            day: day.day.number(),
+            part: part.number(),
+            answer: answer.to_string(),
+            outcome,
+        });
+    }
+}
+
+/// Posts `answer`, unless the log already rules it out, and records the outcome.
+pub fn submit(
+    client: &AocClient,
+    log: &mut SubmissionLog,
+    day: CalendarDay,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome> {
+    let answer = answer.to_string();
+    if let Some(reason) = log.refusal(day, part, &answer) {
+        bail!(
+            "Refusing to submit {} for {} part {}: {}",
+            answer,
+            day,
+            part.number(),
+            reason
+        );
+    }
+    let level = part.number().to_string();
+    let body = client.post_form(
+        &format!("{}/answer", client.day_url(day)),
+        &[("level", &level), ("answer", &answer)],
+    )?;
+    let outcome = parse_response(&body);
+    if !matches!(outcome, Outcome::RateLimited | Outcome::Unknown) {
+        log.record(day, part, &answer, outcome);
+        log.save()?;
+    }
+    Ok(outcome)
+}
+
+/// Solves a day/part and submits the answer, failing unless it's accepted.
+pub fn solve_and_submit(
+    client: &AocClient,
+    log: &mut SubmissionLog,
+    day: CalendarDay,
+    part: Part,
+    input: Option<&InputSource>,
+) -> Result<()> {
+    let (_, inputs) = load_inputs(day.year, vec![day.day], &[part], input, None)?.remove(0);
+    let progress = ProgressBar::show();
+    let run = run_part(registry::solver(day)?, day.day, part, &inputs[0].1)?;
+    drop(progress);
+    // `run_part` returns errors rather than failed runs, so there's always an answer here
+    let answer = run.answer.map_err(anyhow::Error::msg)?;
+    let outcome = submit(client, log, day, part, &answer)?;
+    println!(
+        "Day {} Part {}: {} is {}",
+        day.day,
+        part.number(),
+        answer,
+        outcome
+    );
+    if outcome != Outcome::Correct {
+        bail!("Answer was not accepted");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::client::{test_server, RateLimiter};
+    use crate::utils::models::Day;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_response() {
+        let body = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        assert_eq!(parse_response(body), Outcome::TooLow);
+        assert_eq!(
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently; you have to wait.</p>"),
+            Outcome::RateLimited
+        );
+        assert_eq!(parse_response("<html></html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut log = SubmissionLog::default();
+        let day = CalendarDay::new(Year::new(2025), Day::new(3));
+        let part = Part::Part1;
+        log.record(day, part, "100", Outcome::TooLow);
+        log.record(day, part, "200", Outcome::TooHigh);
+        log.record(day, part, "150", Outcome::Wrong);
+
+        assert!(log.refusal(day, part, "100").is_some());
+        assert!(log.refusal(day, part, "50").is_some());
+        assert!(log.refusal(day, part, "250").is_some());
+        assert!(log.refusal(day, part, "150").is_some());
+        assert!(log.refusal(day, part, "120").is_none());
+        assert!(log.refusal(day, Part::Part2, "250").is_none());
+
+        let other_year = CalendarDay::new(Year::new(2024), Day::new(3));
+        assert!(log.refusal(other_year, part, "250").is_none());
+
+        log.record(day, part, "120", Outcome::Correct);
+        assert!(log.refusal(day, part, "130").is_some());
+    }
+
+    #[test]
+    fn test_submit_records_bounds() {
+        let (url, requests) = test_server::serve(vec![
+            "<p>That's not the right answer; your answer is too high.</p>",
+        ]);
+        let dir = std::env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let client = AocClient::new(
+            &url,
+            "secret".to_string(),
+            RateLimiter::new(dir.join("last_request"), Duration::ZERO),
+        );
+        let log_path = dir.join("submissions.json");
+        let mut log = SubmissionLog::load(&log_path).unwrap();
+
+        let outcome = submit(
+            &client,
+            &mut log,
+            CalendarDay::new(Year::new(2025), Day::new(1)),
+            Part::Part2,
+            &Answer::Int(500),
+        );
+        assert_eq!(outcome.unwrap(), Outcome::TooHigh);
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("POST /2025/day/1/answer"));
+        assert_eq!(request.body, "level=2&answer=500");
+
+        // Refused from the saved log, without another request
+        let mut log = SubmissionLog::load(&log_path).unwrap();
+        assert!(submit(
+            &client,
+            &mut log,
+            CalendarDay::new(Year::new(2025), Day::new(1)),
+            Part::Part2,
+            &Answer::Int(600)
+        )
+        .is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+use crate::runner::alloc::format_bytes;
+use crate::runner::guard::Failure;
+use crate::runner::PartRun;
+use crate::utils::answer::Answer;
+use itertools::Itertools;
+
+const HEADERS: [&str; 5] = ["Day", "Part", "Answer", "Parse", "Solve"];
+const ALLOC_HEADERS: [&str; 3] = ["Allocs", "Allocated", "Peak heap"];
+
+fn answer_cell(answer: &Result<Answer, Failure>) -> String {
+    match answer {
+        Ok(Answer::MultiLine(lines)) => format!("({} lines)", lines.len()),
+        Ok(answer) => answer.to_string(),
+        Err(failure) => failure.summary(),
+    }
+}
+
+fn parse_cell(run: &PartRun) -> String {
+    // A part that reused an earlier part's parsed input took no time to parse
+    if run.parse_time.is_zero() {
+        "-".to_string()
+    } else {
+        format!("{:.2?}", run.parse_time)
+    }
+}
+
+fn row(run: &PartRun) -> Vec<String> {
+    vec![
+        run.day.number().to_string(),
+        run.part.number().to_string(),
+        answer_cell(&run.answer),
+        parse_cell(run),
+        format!("{:.2?}", run.solve_time),
+    ]
+}
+
+fn alloc_cells(run: &PartRun) -> Vec<String> {
+    match run.allocations {
+        Some(stats) => vec![
+            stats.allocations.to_string(),
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak_bytes),
+        ],
+        None => vec!["-".to_string(); ALLOC_HEADERS.len()],
+    }
+}
+
+/// Renders rows of cells as a plain text table with left aligned columns.
+pub fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r[i].chars().count())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!(" {:<width$} ", cell, width = width))
+            .join("|")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(headers.to_vec())];
+    lines.push(widths.iter().map(|w| "-".repeat(w + 2)).join("+"));
+    lines.extend(
+        rows.iter()
+            .map(|r| format_row(r.iter().map(String::as_str).collect())),
+    );
+    lines.join("\n")
+}
+
+/// Renders a summary table of runs, one row per day/part, with allocation columns when
+/// allocations were counted.
+pub fn render(runs: &[PartRun]) -> String {
+    if runs.iter().all(|run| run.allocations.is_none()) {
+        return render_table(&HEADERS, &runs.iter().map(row).collect_vec());
+    }
+    let headers = HEADERS.iter().chain(&ALLOC_HEADERS).copied().collect_vec();
+    let rows = runs
+        .iter()
+        .map(|run| row(run).into_iter().chain(alloc_cells(run)).collect())
+        .collect_vec();
+    render_table(&headers, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::alloc::AllocStats;
+    use crate::utils::models::{Day, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn test_render() {
+        let runs = vec![PartRun {
+            day: Day::new(3),
+            part: Part::Part2,
+            answer: Ok(Answer::Int(123456)),
+            input: "inputs/2025/day_3/part1.txt".to_string(),
+            input_hash: String::new(),
+            parse_time: Duration::from_millis(1),
+            solve_time: Duration::from_millis(20),
+            allocations: None,
+        }];
+        let table = render(&runs);
+        let lines = table.lines().collect_vec();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], " Day | Part | Answer | Parse  | Solve");
+        assert_eq!(lines[2], " 3   | 2    | 123456 | 1.00ms | 20.00ms");
+    }
+
+    #[test]
+    fn test_render_allocations() {
+        let runs = vec![PartRun {
+            day: Day::new(8),
+            part: Part::Part1,
+            answer: Ok(Answer::Int(40)),
+            input: String::new(),
+            input_hash: String::new(),
+            parse_time: Duration::from_millis(1),
+            solve_time: Duration::from_millis(2),
+            allocations: Some(AllocStats {
+                allocations: 12,
+                bytes: 4096,
+                peak_bytes: 2048,
+            }),
+        }];
+        let table = render(&runs);
+        assert!(
+            table.ends_with("| 12     | 4.00 KiB  | 2.00 KiB"),
+            "{}",
+            table
+        );
+    }
+}
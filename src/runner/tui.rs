@@ -0,0 +1,323 @@
+use crate::runner::inputs::load_inputs;
+use crate::runner::visualize::visualize_part;
+use crate::utils::models::{CalendarDay, Day, InputSource, Part, Year};
+use crate::utils::registry;
+use crate::utils::visualize::{self, colour, Frame};
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use std::path::Path;
+use std::time::Duration;
+
+/// How long each step is shown for while playing.
+const PLAY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How far PageUp/PageDown jump.
+const JUMP: usize = 10;
+
+/// How far the arrow keys scroll a state bigger than the terminal.
+const SCROLL: u16 = 4;
+
+const HELP: &str = "←/→ step  PgUp/PgDn ±10  Home/End first/last  space play/pause  \
+                    ↑/↓/a/d scroll  q quit";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Step(isize),
+    First,
+    Last,
+    TogglePlay,
+    Scroll(i16, i16),
+    Quit,
+}
+
+fn action(key: KeyCode) -> Option<Action> {
+    let s = SCROLL as i16;
+    match key {
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('n') => Some(Action::Step(1)),
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('p') => Some(Action::Step(-1)),
+        KeyCode::PageDown => Some(Action::Step(JUMP as isize)),
+        KeyCode::PageUp => Some(Action::Step(-(JUMP as isize))),
+        KeyCode::Home | KeyCode::Char('g') => Some(Action::First),
+        KeyCode::End | KeyCode::Char('G') => Some(Action::Last),
+        KeyCode::Char(' ') => Some(Action::TogglePlay),
+        KeyCode::Up | KeyCode::Char('w') => Some(Action::Scroll(0, -s)),
+        KeyCode::Down | KeyCode::Char('s') => Some(Action::Scroll(0, s)),
+        KeyCode::Char('a') => Some(Action::Scroll(-s, 0)),
+        KeyCode::Char('d') => Some(Action::Scroll(s, 0)),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+        _ => None,
+    }
+}
+
+fn counters_width(frames: &[Frame]) -> u16 {
+    let widest = frames
+        .iter()
+        .flat_map(|frame| {
+            let counters = frame.counters.iter();
+            let widths =
+                counters.map(|(name, value)| name.chars().count() + value.chars().count() + 2);
+            widths.chain([frame.caption.chars().count()])
+        })
+        .max()
+        .unwrap_or(0);
+    // Leaves room for the borders and a little space
+    widest.min(u16::MAX as usize - 4) as u16 + 4
+}
+
+/// Which frame is shown, and how it's scrolled.
+struct Stepper<'a> {
+    title: &'a str,
+    frames: &'a [Frame],
+    index: usize,
+    playing: bool,
+    /// Columns and rows scrolled past
+    scroll: (u16, u16),
+    /// Wide enough for every step's counters, so the layout doesn't jump around
+    counters_width: u16,
+}
+
+impl<'a> Stepper<'a> {
+    fn new(title: &'a str, frames: &'a [Frame]) -> Self {
+        Self {
+            title,
+            frames,
+            index: 0,
+            playing: false,
+            scroll: (0, 0),
+            counters_width: counters_width(frames),
+        }
+    }
+
+    /// Returns false once it's time to quit.
+    fn apply(&mut self, action: Action) -> bool {
+        let last = self.frames.len() - 1;
+        match action {
+            Action::Step(by) => {
+                self.index = self.index.saturating_add_signed(by).min(last);
+            }
+            Action::First => self.index = 0,
+            Action::Last => self.index = last,
+            // Playing from the end starts again from the beginning
+            Action::TogglePlay if !self.playing && self.index == last => {
+                self.index = 0;
+                self.playing = true;
+            }
+            Action::TogglePlay => self.playing = !self.playing,
+            Action::Scroll(x, y) => {
+                self.scroll.0 = self.scroll.0.saturating_add_signed(x);
+                self.scroll.1 = self.scroll.1.saturating_add_signed(y);
+            }
+            Action::Quit => return false,
+        }
+        true
+    }
+
+    /// Moves on a step while playing, stopping at the end.
+    fn tick(&mut self) {
+        if self.index + 1 < self.frames.len() {
+            self.index += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+
+    fn previous(&self) -> Option<&Frame> {
+        self.index.checked_sub(1).map(|i| &self.frames[i])
+    }
+
+    /// The state, with the cells that changed in this step highlighted.
+    fn state(&self) -> Paragraph<'_> {
+        let frame = &self.frames[self.index];
+        let previous = self.previous();
+        let lines = frame
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                let spans = row.iter().enumerate().map(|(x, &cell)| {
+                    let [r, g, b] = colour(cell);
+                    let mut style = Style::default().fg(Color::Rgb(r, g, b));
+                    let before = previous.and_then(|p| p.rows.get(y)?.get(x));
+                    if before.is_some_and(|&before| before != cell) {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    Span::styled(cell.to_string(), style)
+                });
+                Line::from(spans.collect_vec())
+            })
+            .collect_vec();
+        Paragraph::new(lines)
+            .scroll((self.scroll.1, self.scroll.0))
+            .block(Block::bordered().title(" State "))
+    }
+
+    /// The step's caption and counters, with counters that changed in this step in bold.
+    fn counters(&self) -> Paragraph<'_> {
+        let frame = &self.frames[self.index];
+        let before = self.previous().map(|p| &p.counters);
+        let mut lines = vec![Line::styled(
+            frame.caption.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        lines.push(Line::default());
+        for (name, value) in &frame.counters {
+            let changed =
+                before.is_some_and(|before| !before.contains(&(name.clone(), value.clone())));
+            let style = if changed {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("{}: ", name)),
+                Span::styled(value.as_str(), style),
+            ]));
+        }
+        Paragraph::new(lines).block(Block::bordered().title(" Counters "))
+    }
+
+    fn draw(&self, f: &mut ratatui::Frame) {
+        let [header, body, help] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(f.area());
+        let [state, counters] =
+            Layout::horizontal([Constraint::Min(10), Constraint::Length(self.counters_width)])
+                .areas::<2>(body);
+
+        let status = format!(
+            "{}  step {}/{}{}",
+            self.title,
+            self.index + 1,
+            self.frames.len(),
+            if self.playing { "  ▶ playing" } else { "" }
+        );
+        f.render_widget(
+            Line::styled(status, Style::default().add_modifier(Modifier::BOLD)),
+            header,
+        );
+        f.render_widget(self.state(), state);
+        f.render_widget(self.counters(), counters);
+        f.render_widget(
+            Line::styled(HELP, Style::default().fg(Color::DarkGray)),
+            help,
+        );
+    }
+}
+
+/// Shows `frames` full screen, stepping between them with the keyboard, until `q` is pressed.
+pub fn step_through(title: &str, frames: &[Frame]) -> Result<()> {
+    if frames.is_empty() {
+        bail!("There are no steps to show");
+    }
+    let mut stepper = Stepper::new(title, frames);
+    let mut terminal = ratatui::try_init()?;
+    let result = (|| -> Result<()> {
+        loop {
+            terminal.draw(|f| stepper.draw(f))?;
+            if stepper.playing && !event::poll(PLAY_INTERVAL)? {
+                stepper.tick();
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                if let Some(action) = action(key.code) {
+                    if !stepper.apply(action) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    })();
+    ratatui::restore();
+    result
+}
+
+/// Steps through the frames of solving a day/part, from `input` or example N if given.
+pub fn step(
+    year: Year,
+    day: Day,
+    part: Part,
+    input: Option<&Path>,
+    example: Option<u8>,
+) -> Result<()> {
+    let calendar_day = CalendarDay::new(year, day);
+    let visualizer = visualize::find(calendar_day).with_context(|| {
+        format!(
+            "Day {} of {} has no visualization to step through",
+            day, year
+        )
+    })?;
+    // Stdin is where the keys come from, so the input has to be a file
+    let input = input.map(|path| InputSource::File(path.to_path_buf()));
+    let (day, inputs) = load_inputs(year, vec![day], &[part], input.as_ref(), example)?.remove(0);
+    let solver = registry::solver(calendar_day)?;
+    let frames = visualize_part(solver, visualizer, day, part, &inputs[0].1)?;
+    let title = format!("{} Day {} Part {}", year, day, part.number());
+    step_through(&title, &frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn frames() -> Vec<Frame> {
+        vec![
+            Frame::from_lines("Start", &["@@", ".@"]).with_counter("removed", 0),
+            Frame::from_lines("Wave 1", &["x@", ".x"]).with_counter("removed", 2),
+        ]
+    }
+
+    #[test]
+    fn test_stepping() {
+        let frames = frames();
+        let mut stepper = Stepper::new("Day 4 Part 2", &frames);
+        assert!(stepper.apply(Action::Step(-1)));
+        assert_eq!(stepper.index, 0);
+        stepper.apply(Action::Step(JUMP as isize));
+        assert_eq!(stepper.index, 1);
+        stepper.apply(Action::TogglePlay);
+        assert_eq!((stepper.index, stepper.playing), (0, true));
+        stepper.tick();
+        stepper.tick();
+        assert_eq!((stepper.index, stepper.playing), (1, false));
+        assert!(!stepper.apply(action(KeyCode::Char('q')).unwrap()));
+    }
+
+    #[test]
+    fn test_draw() {
+        let frames = frames();
+        let mut stepper = Stepper::new("Day 4 Part 2", &frames);
+        stepper.apply(Action::Step(1));
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal.draw(|f| stepper.draw(f)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let lines = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect_vec();
+        assert!(lines[0].starts_with("Day 4 Part 2  step 2/2"));
+        assert!(lines[2].contains("│x@") && lines[2].contains("│Wave 1"));
+        assert!(lines[4].contains("removed: 2"));
+        // The cells removed in this step are highlighted
+        let removed = buffer[(1, 2)].modifier;
+        assert!(removed.contains(Modifier::REVERSED));
+        assert!(!buffer[(2, 2)].modifier.contains(Modifier::REVERSED));
+    }
+}
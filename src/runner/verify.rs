@@ -0,0 +1,271 @@
+use crate::runner::inputs::load_inputs;
+use crate::runner::progress::ProgressBar;
+use crate::runner::table::render_table;
+use crate::runner::{report_failures, run_day, PartRun};
+use crate::utils::load::MissingInputError;
+use crate::utils::models::{CalendarDay, Day, Part, PartSelection, Year};
+use crate::utils::registry;
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const HEADERS: [&str; 5] = ["Day", "Part", "Answer", "Expected", "Result"];
+
+/// Expected answers, read from a TOML file with a table per year and day, such as:
+///
+/// ```toml
+/// [2025.day_1]
+/// part_1 = 1234
+/// part_2 = "some text"
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers(BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>);
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read answers from {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        Ok(Self(toml::from_str(contents)?))
+    }
+
+    pub fn get(&self, year: Year, day: Day, part: Part) -> Option<String> {
+        let value = self
+            .0
+            .get(&year.to_string())?
+            .get(&format!("day_{}", day.number()))?
+            .get(&format!("part_{}", part.number()))?;
+        Some(match value {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// The answer didn't match, or the part failed
+    Fail,
+    /// There's no expected answer, or no input to run on
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// A run checked against its expected answer.
+pub struct Verification {
+    pub day: Day,
+    pub part: Part,
+    /// Not set when the day has no input to run on
+    pub run: Option<PartRun>,
+    pub expected: Option<String>,
+    pub verdict: Verdict,
+}
+
+pub fn verify(run: PartRun, year: Year, expected: &ExpectedAnswers) -> Verification {
+    let expected = expected.get(year, run.day, run.part);
+    let verdict = match (&run.answer, &expected) {
+        (Err(_), _) => Verdict::Fail,
+        (Ok(_), None) => Verdict::Missing,
+        (Ok(answer), Some(e)) if *e == answer.to_string() => Verdict::Pass,
+        (Ok(_), Some(_)) => Verdict::Fail,
+    };
+    Verification {
+        day: run.day,
+        part: run.part,
+        run: Some(run),
+        expected,
+        verdict,
+    }
+}
+
+/// A day/part that couldn't be checked, as it has no input.
+pub fn no_input(day: Day, part: Part, year: Year, expected: &ExpectedAnswers) -> Verification {
+    Verification {
+        day,
+        part,
+        run: None,
+        expected: expected.get(year, day, part),
+        verdict: Verdict::Missing,
+    }
+}
+
+fn row(verification: &Verification) -> Vec<String> {
+    let answer = match &verification.run {
+        Some(run) => run.answer_text(),
+        None => "no input".to_string(),
+    };
+    vec![
+        verification.day.number().to_string(),
+        verification.part.number().to_string(),
+        answer.replace('\n', "\\n"),
+        verification
+            .expected
+            .clone()
+            .unwrap_or_default()
+            .replace('\n', "\\n"),
+        verification.verdict.to_string(),
+    ]
+}
+
+/// Renders a table of verification results, one row per day/part.
+pub fn render(verifications: &[Verification]) -> String {
+    render_table(&HEADERS, &verifications.iter().map(row).collect_vec())
+}
+
+/// Runs `days` and checks their answers against the expected answers in `answers`, failing if
+/// any don't match or fail to run. Days without an input are reported as missing.
+pub fn run_verify(
+    year: Year,
+    days: Vec<Day>,
+    part: PartSelection,
+    answers: &Path,
+    budget: Option<Duration>,
+) -> Result<()> {
+    let expected = ExpectedAnswers::load(answers)?;
+    let parts = part.parts();
+    let mut verifications = vec![];
+    let progress = ProgressBar::show();
+    for day in days {
+        let inputs = match load_inputs(year, vec![day], &parts, None, None) {
+            Ok(inputs) => inputs,
+            Err(e) if e.is::<MissingInputError>() => {
+                tracing::debug!("{:#}", e);
+                verifications.extend(parts.iter().map(|&p| no_input(day, p, year, &expected)));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        for (day, inputs) in inputs {
+            let solver = registry::solver(CalendarDay::new(year, day))?;
+            let runs = run_day(solver, day, &inputs, budget);
+            verifications.extend(runs.into_iter().map(|run| verify(run, year, &expected)));
+        }
+    }
+    drop(progress);
+    report_failures(verifications.iter().filter_map(|v| v.run.as_ref()));
+    println!("{}", render(&verifications));
+
+    let failures = verifications
+        .iter()
+        .filter(|v| v.verdict == Verdict::Fail)
+        .count();
+    if failures > 0 {
+        bail!(
+            "{} part(s) failed or did not match {}",
+            failures,
+            answers.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::guard::Failure;
+    use crate::utils::answer::Answer;
+
+    fn run(day: Day, part: Part, answer: Answer) -> PartRun {
+        PartRun {
+            day,
+            part,
+            answer: Ok(answer),
+            input: "<stdin>".to_string(),
+            input_hash: String::new(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let expected = ExpectedAnswers::parse(
+            r#"
+[2025.day_1]
+part_1 = 3
+part_2 = "6"
+
+[2025.day_2]
+part_1 = 1227775554
+"#,
+        )
+        .unwrap();
+        let year = Year::new(2025);
+
+        let verdicts = [
+            run(Day::new(1), Part::Part1, Answer::Int(3)),
+            run(Day::new(1), Part::Part2, Answer::Int(7)),
+            run(Day::new(2), Part::Part1, Answer::Int(1227775554)),
+            run(Day::new(2), Part::Part2, Answer::Int(1)),
+        ]
+        .into_iter()
+        .map(|r| verify(r, year, &expected).verdict)
+        .collect_vec();
+        assert_eq!(
+            verdicts,
+            vec![
+                Verdict::Pass,
+                Verdict::Fail,
+                Verdict::Pass,
+                Verdict::Missing
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_failed_and_missing() {
+        let expected = ExpectedAnswers::parse("[2025.day_1]\npart_1 = 3\n").unwrap();
+        let year = Year::new(2025);
+        // A part that fails is a failure even without an expected answer to compare against
+        let failed = PartRun {
+            answer: Err(Failure::Timeout(Duration::from_secs(1))),
+            ..run(Day::new(3), Part::Part1, Answer::Int(0))
+        };
+        assert_eq!(verify(failed, year, &expected).verdict, Verdict::Fail);
+
+        let missing = no_input(Day::new(1), Part::Part1, year, &expected);
+        assert_eq!(missing.verdict, Verdict::Missing);
+        assert_eq!(row(&missing), ["1", "1", "no input", "3", "missing"]);
+    }
+
+    #[test]
+    fn test_verify_years_sharing_a_day() {
+        let expected = ExpectedAnswers::parse(
+            r#"
+[2024.day_1]
+part_1 = 11
+
+[2025.day_1]
+part_1 = 3
+"#,
+        )
+        .unwrap();
+        let check = |year: u16, answer: i64| {
+            let run = run(Day::new(1), Part::Part1, Answer::Int(answer));
+            verify(run, Year::new(year), &expected).verdict
+        };
+        assert_eq!(check(2024, 11), Verdict::Pass);
+        assert_eq!(check(2024, 3), Verdict::Fail);
+        assert_eq!(check(2025, 3), Verdict::Pass);
+        assert_eq!(check(2023, 3), Verdict::Missing);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::runner::inputs::DayInputs;
+use crate::runner::parse_input;
+use crate::utils::advent_day::Solver;
+use crate::utils::load::{day_dir, PuzzleInput};
+use crate::utils::models::{CalendarDay, Day, Part, Year};
+use crate::utils::registry;
+use crate::utils::visualize::{self, colour, Frame, Rgb, Visualizer};
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Images are scaled up to about this many pixels across, so small grids stay visible.
+const TARGET_SIZE: usize = 600;
+
+/// The most pixels across a single cell is drawn with.
+const MAX_SCALE: usize = 16;
+
+/// How long an animation should take at most, in hundredths of a second. Long simulations
+/// speed up to fit, down to the fastest frame rate viewers reliably play.
+const MAX_ANIMATION: usize = 3000;
+const MIN_FRAME_DELAY: usize = 2;
+const MAX_FRAME_DELAY: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum FrameFormat {
+    /// Every frame and its caption in one text file
+    Ascii,
+    /// A binary PPM image per frame
+    Ppm,
+    /// A PNG image per frame
+    Png,
+    /// One looping animated GIF
+    Gif,
+}
+
+/// Parses `input` and collects the frames of solving `part` from it.
+pub fn visualize_part(
+    solver: &dyn Solver,
+    visualizer: &dyn Visualizer,
+    day: Day,
+    part: Part,
+    input: &PuzzleInput,
+) -> Result<Vec<Frame>> {
+    let _span =
+        tracing::info_span!("visualize", day = day.number(), part = part.number()).entered();
+    let parsed = parse_input(solver, day, input)?;
+    visualizer
+        .frames(part, &parsed.input)
+        .with_context(|| format!("Day {} Part {} couldn't be visualized", day, part.number()))
+}
+
+/// Writes `frames` into `dir`, creating it if needed, and returns the files written.
+pub fn write_frames(dir: &Path, frames: &[Frame], format: FrameFormat) -> Result<Vec<PathBuf>> {
+    if frames.is_empty() {
+        bail!("There are no frames to write");
+    }
+    fs::create_dir_all(dir).with_context(|| format!("Couldn't create {}", dir.display()))?;
+    let frame_path =
+        |i: usize, extension: &str| dir.join(format!("frame_{:04}.{}", i + 1, extension));
+    let mut written = vec![];
+    match format {
+        FrameFormat::Ascii => {
+            let path = dir.join("frames.txt");
+            fs::write(&path, render_ascii(frames))?;
+            written.push(path);
+        }
+        FrameFormat::Ppm => {
+            for (i, frame) in frames.iter().enumerate() {
+                let path = frame_path(i, "ppm");
+                fs::write(&path, encode_ppm(&Image::draw(frame)))?;
+                written.push(path);
+            }
+        }
+        FrameFormat::Png => {
+            for (i, frame) in frames.iter().enumerate() {
+                let path = frame_path(i, "png");
+                write_png(&path, &Image::draw(frame))?;
+                written.push(path);
+            }
+        }
+        FrameFormat::Gif => {
+            let path = dir.join("animation.gif");
+            write_gif(&path, frames)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// Writes the frames of each loaded day/part into `dir`, under `YYYY/day_N/part_P/`, skipping
+/// days without a visualization.
+pub fn write_visualizations(
+    year: Year,
+    inputs: &[DayInputs],
+    dir: &Path,
+    format: FrameFormat,
+) -> Result<()> {
+    for (day, inputs) in inputs {
+        let calendar_day = CalendarDay::new(year, *day);
+        let Some(visualizer) = visualize::find(calendar_day) else {
+            tracing::warn!("Day {} has no visualization", day);
+            continue;
+        };
+        let solver = registry::solver(calendar_day)?;
+        for (part, input) in inputs {
+            let frames = visualize_part(solver, visualizer, *day, *part, input)?;
+            let part_dir = dir
+                .join(day_dir(calendar_day))
+                .join(format!("part_{}", part.number()));
+            write_frames(&part_dir, &frames, format)?;
+            tracing::info!(
+                "Wrote {} frames of Day {} Part {} to {}",
+                frames.len(),
+                day,
+                part.number(),
+                part_dir.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn render_ascii(frames: &[Frame]) -> String {
+    frames.iter().map(|frame| format!("{}\n", frame)).join("\n")
+}
+
+/// How many pixels across each cell of a `columns` by `rows` grid is drawn with.
+fn cell_scale(columns: usize, rows: usize) -> usize {
+    (TARGET_SIZE / columns.max(rows).max(1)).clamp(1, MAX_SCALE)
+}
+
+/// A frame drawn as pixels, with each cell scaled up to a square block. Pixels are colours,
+/// or indexes into a palette of them.
+struct Image<P = Rgb> {
+    width: usize,
+    height: usize,
+    pixels: Vec<P>,
+}
+
+impl<P: Copy> Image<P> {
+    /// Draws `frame` on a grid of `columns` by `rows` cells, padding it out with empty space,
+    /// with each cell's pixels set by `paint`.
+    fn draw_sized(frame: &Frame, columns: usize, rows: usize, paint: impl Fn(char) -> P) -> Self {
+        let scale = cell_scale(columns, rows);
+        let (width, height) = (columns * scale, rows * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let row = frame.rows.get(y / scale);
+            for x in 0..width {
+                let cell = row.and_then(|row| row.get(x / scale)).unwrap_or(&' ');
+                pixels.push(paint(*cell));
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+impl Image {
+    fn draw(frame: &Frame) -> Self {
+        Self::draw_sized(frame, frame.width(), frame.height(), colour)
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+}
+
+fn encode_ppm(image: &Image) -> Vec<u8> {
+    let mut ppm = format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+    ppm.extend(image.bytes());
+    ppm
+}
+
+fn write_png(path: &Path, image: &Image) -> Result<()> {
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, image.width as u32, image.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&image.bytes())?;
+    Ok(())
+}
+
+/// Writes `frames` as an animation, drawing and encoding them one at a time so only one is
+/// held in memory.
+fn write_gif(path: &Path, frames: &[Frame]) -> Result<()> {
+    // Every frame is drawn at the same size, so the animation doesn't jump around
+    let columns = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let rows = frames.iter().map(Frame::height).max().unwrap_or(0);
+
+    // The palette holds the colour of every cell in any frame, plus the padding around them
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+    let mut colours = vec![];
+    let cells = frames
+        .iter()
+        .flat_map(|frame| frame.rows.iter().flatten())
+        .chain([&' '])
+        .unique();
+    let mut indexes: HashMap<char, u8> = HashMap::new();
+    for &cell in cells {
+        let rgb = colour(cell);
+        let index = match palette.get(&rgb) {
+            Some(index) => *index,
+            None => {
+                let index =
+                    u8::try_from(palette.len()).context("GIFs can't have more than 256 colours")?;
+                palette.insert(rgb, index);
+                colours.extend(rgb);
+                index
+            }
+        };
+        indexes.insert(cell, index);
+    }
+
+    let scale = cell_scale(columns, rows);
+    let (width, height) = (columns * scale, rows * scale);
+    let too_big = || format!("A {}x{} image is too big for a GIF", width, height);
+    let (width, height) = (
+        u16::try_from(width).with_context(too_big)?,
+        u16::try_from(height).with_context(too_big)?,
+    );
+
+    let delay = (MAX_ANIMATION / frames.len()).clamp(MIN_FRAME_DELAY, MAX_FRAME_DELAY) as u16;
+    let mut file = BufWriter::new(File::create(path)?);
+    {
+        let mut encoder = gif::Encoder::new(&mut file, width, height, &colours)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in frames {
+            let image = Image::draw_sized(frame, columns, rows, |cell| indexes[&cell]);
+            let mut frame = gif::Frame::from_indexed_pixels(width, height, image.pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+    }
+    file.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn frames() -> Vec<Frame> {
+        vec![
+            Frame::from_lines("Start", &["@@", ".@"]),
+            Frame::from_lines("Wave 1", &["x@", ".x"]),
+        ]
+    }
+
+    #[test]
+    fn test_render_ascii() {
+        assert_eq!(render_ascii(&frames()), "Start\n@@\n.@\n\nWave 1\nx@\n.x\n");
+    }
+
+    #[test]
+    fn test_encode_ppm() {
+        let image = Image::draw(&frames()[0]);
+        assert_eq!((image.width, image.height), (32, 32));
+        let ppm = encode_ppm(&image);
+        assert!(ppm.starts_with(b"P6\n32 32\n255\n"));
+        assert_eq!(ppm.len(), 13 + 32 * 32 * 3);
+        // The top left cell is a roll, the bottom left is empty
+        assert_eq!(&ppm[13..16], &colour('@'));
+        assert_eq!(&ppm[ppm.len() - 32 * 3..][..3], &colour('.'));
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let dir = env::temp_dir().join(format!("aoc_frames_{}", std::process::id()));
+        let files = write_frames(&dir, &frames(), FrameFormat::Png).unwrap();
+        assert_eq!(
+            files,
+            [dir.join("frame_0001.png"), dir.join("frame_0002.png")]
+        );
+        assert!(fs::read(&files[0]).unwrap().starts_with(b"\x89PNG"));
+
+        let files = write_frames(&dir, &frames(), FrameFormat::Gif).unwrap();
+        assert!(fs::read(&files[0]).unwrap().starts_with(b"GIF89a"));
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&files[0]).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (32, 32));
+        let mut decoded = 0;
+        while decoder.read_next_frame().unwrap().is_some() {
+            decoded += 1;
+        }
+        assert_eq!(decoded, 2);
+        assert!(write_frames(&dir, &[], FrameFormat::Gif).is_err());
+
+        // Wider than a GIF can be, even at a pixel per cell
+        let wide = Frame::from_lines("Wide", &[&".".repeat(70_000)]);
+        assert!(write_frames(&dir, &[wide], FrameFormat::Gif).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+use crate::runner::guard::wait_for_overrunning;
+use crate::runner::inputs::InputSelection;
+use crate::runner::output::{self, OutputFormat};
+use crate::runner::{report_failures, run_days, PartRun};
+use crate::utils::answer::Answer;
+use crate::utils::load::day_dir;
+use crate::utils::models::{CalendarDay, InputSource, Year};
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time and length of every file under the watched paths.
+pub type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), snapshot);
+        }
+    } else {
+        snapshot.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+    }
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        collect(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Polls `paths` until a file is added, removed or modified, returning the new snapshot.
+pub fn wait_for_change(paths: &[PathBuf], previous: &Snapshot, interval: Duration) -> Snapshot {
+    loop {
+        thread::sleep(interval);
+        let current = snapshot(paths);
+        if &current != previous {
+            return current;
+        }
+    }
+}
+
+/// The files and directories whose changes re-run `selection`: the selected days' inputs, and
+/// their examples or the `--input` file if those are used.
+pub fn watched_paths(year: Year, selection: &InputSelection) -> Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+    for day in selection.days(year)? {
+        let dir = day_dir(CalendarDay::new(year, day));
+        paths.push(Path::new("inputs").join(&dir));
+        if selection.example.is_some() {
+            paths.push(Path::new("examples").join(&dir));
+        }
+    }
+    match &selection.input {
+        Some(InputSource::File(path)) => paths.push(path.clone()),
+        Some(InputSource::Stdin) => bail!("--watch can't be used with input from stdin"),
+        None => {}
+    }
+    Ok(paths)
+}
+
+/// Runs `selection`, then re-runs it whenever one of its inputs changes, printing the results
+/// and how the answers changed each time. Only returns if the paths to watch can't be found.
+pub fn watch(
+    year: Year,
+    selection: &InputSelection,
+    format: OutputFormat,
+    budget: Option<Duration>,
+) -> Result<()> {
+    let paths = watched_paths(year, selection)?;
+    let mut snapshot = snapshot(&paths);
+    let mut previous: Option<Vec<PartRun>> = None;
+    loop {
+        // Parts that timed out on the last run would otherwise compete with this one
+        wait_for_overrunning();
+        // Errors are reported but don't stop the watch, since an input may be half written
+        match selection
+            .load(year)
+            .and_then(|inputs| run_days(year, &inputs, budget))
+        {
+            Ok(runs) => {
+                println!("{}", output::render(year, &runs, format)?);
+                report_failures(&runs);
+                if let Some(previous) = &previous {
+                    println!("{}", render_diff(previous, &runs));
+                }
+                previous = Some(runs);
+            }
+            Err(e) => eprintln!("Error: {:?}", e),
+        }
+        tracing::info!(
+            "Watching {} for changes",
+            paths.iter().map(|p| p.display()).join(", ")
+        );
+        snapshot = wait_for_change(&paths, &snapshot, POLL_INTERVAL);
+    }
+}
+
+fn answer_diff(previous: &Answer, current: &Answer) -> String {
+    match (previous, current) {
+        (Answer::MultiLine(old), Answer::MultiLine(new)) => {
+            let mut lines = vec![];
+            for i in 0..old.len().max(new.len()) {
+                match (old.get(i), new.get(i)) {
+                    (Some(a), Some(b)) if a == b => lines.push(format!("  {}", a)),
+                    (a, b) => {
+                        lines.extend(a.map(|a| format!("- {}", a)));
+                        lines.extend(b.map(|b| format!("+ {}", b)));
+                    }
+                }
+            }
+            format!("changed:\n{}", lines.join("\n"))
+        }
+        _ => format!("{} -> {}", previous, current),
+    }
+}
+
+/// Describes how each day/part's answer changed since the previous run.
+pub fn render_diff(previous: &[PartRun], current: &[PartRun]) -> String {
+    current
+        .iter()
+        .map(|run| {
+            let label = format!("Day {} Part {}", run.day, run.part.number());
+            let before = previous
+                .iter()
+                .find(|p| p.day == run.day && p.part == run.part);
+            match before {
+                Some(before) if before.answer == run.answer => {
+                    format!("{}: unchanged ({})", label, run.answer_text())
+                }
+                Some(before) => match (&before.answer, &run.answer) {
+                    (Ok(previous), Ok(current)) => {
+                        format!("{}: {}", label, answer_diff(previous, current))
+                    }
+                    _ => format!(
+                        "{}: {} -> {}",
+                        label,
+                        before.answer_text(),
+                        run.answer_text()
+                    ),
+                },
+                None => format!("{}: {}", label, run.answer_text()),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::guard::Failure;
+    use crate::utils::models::{Day, Part};
+
+    fn run(part: Part, answer: Answer) -> PartRun {
+        PartRun {
+            day: Day::new(1),
+            part,
+            answer: Ok(answer),
+            input: "inputs/2025/day_1/input.txt".to_string(),
+            input_hash: String::new(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_sees_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("input.txt"), "R5").unwrap();
+        let paths = [dir.clone()];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+        assert_eq!(snapshot(&paths), before);
+
+        fs::write(dir.join("input.txt"), "R5\nL10").unwrap();
+        assert_ne!(snapshot(&paths), before);
+        fs::write(dir.join("nested/edge_case.txt"), "L1").unwrap();
+        assert_eq!(snapshot(&paths).len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let previous = [
+            run(Part::Part1, Answer::Int(3)),
+            run(Part::Part2, Answer::Int(6)),
+        ];
+        let current = [
+            run(Part::Part1, Answer::Int(3)),
+            run(Part::Part2, Answer::Int(7)),
+        ];
+        assert_eq!(
+            render_diff(&previous, &current),
+            "Day 1 Part 1: unchanged (3)\nDay 1 Part 2: 6 -> 7"
+        );
+
+        let mut panicked = run(Part::Part2, Answer::Int(0));
+        panicked.answer = Err(Failure::Panic(
+            "attempt to subtract with overflow".to_string(),
+        ));
+        assert_eq!(
+            render_diff(&current[1..], &[panicked]),
+            "Day 1 Part 2: 7 -> panicked: attempt to subtract with overflow"
+        );
+
+        let lines =
+            |lines: &[&str]| Answer::from(lines.iter().map(|l| l.to_string()).collect::<Vec<_>>());
+        assert_eq!(
+            answer_diff(&lines(&["#.", ".#"]), &lines(&["#.", "##", "#"])),
+            "changed:\n  #.\n- .#\n+ ##\n+ #"
+        );
+    }
+}
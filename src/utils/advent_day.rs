@@ -1,14 +1,41 @@
+use crate::utils::answer::Answer;
 use crate::utils::models::Part;
 use anyhow::Result;
-pub trait AdventDay {
-    fn part_1(&self) -> Result<()>;
-    fn part_2(&self) -> Result<()>;
+use std::any::Any;
 
-    fn run(&self, part: Part) -> Result<()> {
+pub trait AdventDay: Sync {
+    /// The parsed puzzle input, shared by both parts
+    type Input: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part_1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part_2(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+/// An input parsed by a [`Solver`]. Only the day that parsed it knows its type.
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// The object-safe form of [`AdventDay`], implemented for every day, so that days with
+/// different `Input` types can be registered and run side by side.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+
+    /// Solves a part from an input this solver parsed. Panics if it was parsed by another day.
+    fn solve(&self, part: Part, input: &ParsedInput) -> Result<Answer>;
+}
+
+impl<T: AdventDay> Solver for T {
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(Box::new(AdventDay::parse(self, input)?))
+    }
+
+    fn solve(&self, part: Part, input: &ParsedInput) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<T::Input>()
+            .expect("input was parsed by a different day");
         match part {
-            Part::Part1 => self.part_1()?,
-            Part::Part2 => self.part_2()?,
+            Part::Part1 => self.part_1(input),
+            Part::Part2 => self.part_2(input),
         }
-        Ok(())
     }
 }
@@ -26,10 +26,10 @@ pub struct Coordinate3D {
     pub z: i64,
 }
 
-impl TryFrom<&String> for Coordinate3D {
+impl TryFrom<&str> for Coordinate3D {
     type Error = ParseCoordinateError;
 
-    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
         let split = get_coord_values(value, 3)?;
         Ok(Self {
             x: split[0],
@@ -45,10 +45,10 @@ pub struct Coordinate2D {
     pub y: i64,
 }
 
-impl TryFrom<&String> for Coordinate2D {
+impl TryFrom<&str> for Coordinate2D {
     type Error = ParseCoordinateError;
 
-    fn try_from(value: &String) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let split = get_coord_values(value, 2)?;
         Ok(Self {
             x: split[0],
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::fmt::Display;
+
+/// An error at a particular place in a puzzle input, rendered with the offending line and a
+/// caret under the problem, e.g.
+///
+/// ```text
+/// Invalid move: Direction parsing failed
+///  --> inputs/2025/day_1/input.txt:3:1
+///   |
+/// 3 | X12
+///   | ^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// Where the input was read from, if known. Days don't know this, so the runner fills it in
+    pub file: Option<String>,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in characters
+    pub column: usize,
+    /// The whole of the offending line
+    pub line_text: String,
+    /// How many characters of the line to underline
+    pub width: usize,
+}
+
+/// The byte offset of `span` within `input`, which it should be a slice of.
+fn offset_of(input: &str, span: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = span.as_ptr() as usize;
+    if position >= start && position + span.len() <= start + input.len() {
+        position - start
+    } else {
+        // Not a slice of the input, so the best we can do is look for it
+        input.find(span).unwrap_or(0)
+    }
+}
+
+impl Diagnostic {
+    /// An error about `span`, which should be a slice of `input` so its position can be found.
+    /// An empty span points between characters, e.g. at the end of the input for something
+    /// that's missing.
+    pub fn new(input: &str, span: &str, message: impl Display) -> Self {
+        let offset = offset_of(input, span);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line_text = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+        // Only the first line of a span that runs over several is underlined
+        let width = span
+            .lines()
+            .next()
+            .map_or(0, |first| first.trim_end_matches('\r').chars().count());
+        Self {
+            message: message.to_string(),
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column,
+            line_text: line_text.to_string(),
+            width,
+        }
+    }
+
+    /// An error about something missing from the end of `input`.
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::new(input, &input[input.len()..], message)
+    }
+
+    pub fn in_file(mut self, file: impl Display) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string();
+        let pad = " ".repeat(gutter.len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{pad}--> {}:{}:{}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{pad} |")?;
+        writeln!(f, "{gutter} | {}", self.line_text)?;
+        write!(
+            f,
+            "{pad} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_position() {
+        let input = "R5\nL10 X7\nR2";
+        let token = input.split_whitespace().nth(2).unwrap();
+        let diagnostic = Diagnostic::new(input, token, "Invalid move").in_file("day_1.txt");
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 5));
+        assert_eq!(
+            diagnostic.to_string(),
+            "Invalid move\n --> day_1.txt:2:5\n  |\n2 | L10 X7\n  |     ^^"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_at_end() {
+        let diagnostic = Diagnostic::at_end("1-3\n5-8", "Missing separator");
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert!(diagnostic.to_string().ends_with("2 | 5-8\n  |    ^"));
+        assert_eq!(Diagnostic::at_end("", "Empty").line_text, "");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::utils::advent_day::Solver;
+use crate::utils::load::day_dir;
+use crate::utils::models::{CalendarDay, Part};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A puzzle example read from `examples/YYYY/day_N/<name>.txt`, with its expected answers
+/// read from `examples/YYYY/day_N/<name>.toml` (e.g. `part_1 = 13`).
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: BTreeMap<String, toml::Value>,
+}
+
+pub fn examples_dir(day: CalendarDay) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(day_dir(day))
+}
+
+pub fn load_examples(day: CalendarDay) -> Vec<Example> {
+    let dir = examples_dir(day);
+    let mut examples: Vec<Example> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", dir.display(), e))
+        .map(|entry| entry.expect("readable directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .map(|path| {
+            let answers_path = path.with_extension("toml");
+            let answers = fs::read_to_string(&answers_path)
+                .unwrap_or_else(|e| panic!("Couldn't read {}: {}", answers_path.display(), e));
+            Example {
+                name: path.file_stem().unwrap().to_string_lossy().to_string(),
+                input: fs::read_to_string(&path).unwrap(),
+                expected: toml::from_str(&answers)
+                    .unwrap_or_else(|e| panic!("Invalid {}: {}", answers_path.display(), e)),
+            }
+        })
+        .collect();
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+/// Runs `solver` on every example for `day` that has an expected answer for `part_key`
+/// (`part_1` or `part_2`), panicking on the first mismatch.
+pub fn check_examples(solver: &dyn Solver, day: CalendarDay, part_key: &str) {
+    let part = match part_key {
+        "part_1" => Part::Part1,
+        "part_2" => Part::Part2,
+        other => panic!("Unknown part '{}'", other),
+    };
+    let examples = load_examples(day);
+    let mut checked = 0;
+    for example in &examples {
+        let Some(expected) = example.expected.get(part_key) else {
+            continue;
+        };
+        let expected = match expected {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        let answer = solver
+            .parse(&example.input)
+            .and_then(|parsed| solver.solve(part, &parsed));
+        let answer = answer
+            .unwrap_or_else(|e| panic!("{} {} failed on {}: {:?}", day, part_key, example.name, e));
+        assert_eq!(
+            answer.to_string(),
+            expected,
+            "{} {} gave the wrong answer for {}",
+            day,
+            part_key,
+            example.name
+        );
+        checked += 1;
+    }
+    assert!(
+        checked > 0,
+        "No examples in {} have an answer for {}",
+        examples_dir(day).display(),
+        part_key
+    );
+}
+
+/// Generates a test per part that checks `$solver` against the example files for `$day` of
+/// `$year`. Pass the parts explicitly to only test some of them, e.g.
+/// `example_tests!(2025, 8, DayEight, part_2)`.
+macro_rules! example_tests {
+    ($year:literal, $day:literal, $solver:expr) => {
+        $crate::utils::examples::example_tests!($year, $day, $solver, part_1, part_2);
+    };
+    ($year:literal, $day:literal, $solver:expr, $($part:ident),+) => {
+        mod examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $part() {
+                    $crate::utils::examples::check_examples(
+                        &$solver,
+                        $crate::utils::models::CalendarDay::new(
+                            $crate::utils::models::Year::new($year),
+                            $crate::utils::models::Day::new($day),
+                        ),
+                        stringify!($part),
+                    );
+                }
+            )+
+        }
+    };
+}
+
+pub(crate) use example_tests;
@@ -3,12 +3,44 @@ use std::fmt;
 use std::fmt::{Debug, Display};
 use std::slice::Iter;
 
+use crate::utils::diagnostic::Diagnostic;
 use thiserror::Error;
 
 #[derive(Debug, Error, Clone)]
 #[error("Invalid Grid. should be a rectangular grid of values")]
 pub struct ParseGridError;
 
+/// Checks that `input` is a rectangular grid made only of the `allowed` characters, pointing
+/// at the first thing that isn't.
+pub fn check_grid(input: &str, allowed: &[char]) -> Result<(), Diagnostic> {
+    let mut rows = input.split_whitespace().peekable();
+    let width = match rows.peek() {
+        Some(first) => first.chars().count(),
+        None => return Err(Diagnostic::at_end(input, ParseGridError)),
+    };
+    for row in rows {
+        if let Some((idx, c)) = row.char_indices().find(|(_, c)| !allowed.contains(c)) {
+            let expected = allowed.iter().map(|c| format!("'{}'", c)).join(", ");
+            return Err(Diagnostic::new(
+                input,
+                &row[idx..idx + c.len_utf8()],
+                format!("Unexpected '{}', expected one of {}", c, expected),
+            ));
+        }
+        if row.chars().count() != width {
+            return Err(Diagnostic::new(
+                input,
+                row,
+                format!(
+                    "{}: this row isn't {} wide like the first",
+                    ParseGridError, width
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone)]
 pub struct Grid<T: Clone> {
     pub width: usize,
@@ -51,7 +83,7 @@ impl<T: Clone> Grid<T> {
             .collect()
     }
 
-    pub(crate) fn transpose(&self, default: T) -> Grid<T> {
+    pub fn transpose(&self, default: T) -> Grid<T> {
         if self.values.is_empty() || self.values[0].is_empty() {
             return Grid::new(self.width, self.values.clone());
         }
@@ -1,17 +1,168 @@
+use crate::utils::models::{CalendarDay, InputSource, Part};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
 use std::{fs, io};
+use thiserror::Error;
 
-pub fn load_from_file(path: &str) -> Result<Vec<String>, io::Error> {
-    let contents = fs::read_to_string(path)?;
-
-    let lines: Vec<String> = contents
+pub fn load_from_str(contents: &str) -> Vec<String> {
+    contents
         .split_whitespace()
         .filter(|s| !s.is_empty())
         .map(String::from)
+        .collect()
+}
+
+pub fn raw_load_from_str(contents: &str) -> String {
+    contents.trim().to_string()
+}
+
+/// Error when none of the candidate input files exist
+#[derive(Debug, Error)]
+#[error(
+    "No input found for {day} part {}. Searched:{}",
+    .part.number(),
+    .searched.iter().map(|path| format!("\n  {}", path.display())).collect::<String>()
+)]
+pub struct MissingInputError {
+    pub day: CalendarDay,
+    pub part: Part,
+    pub searched: Vec<PathBuf>,
+}
+
+/// A day's directory under `inputs/` or `examples/`, e.g. `2025/day_3`.
+pub fn day_dir(day: CalendarDay) -> PathBuf {
+    PathBuf::from(day.year.to_string()).join(format!("day_{}", day.day))
+}
+
+/// Where a day's downloaded input is stored, relative to the repository root.
+pub fn input_path(day: CalendarDay) -> PathBuf {
+    Path::new("inputs").join(day_dir(day)).join("input.txt")
+}
+
+/// The files that may hold the input for a day/part, most specific first.
+///
+/// Puzzle inputs are read from `inputs/YYYY/day_N/partN.txt` when a part has its own input,
+/// then `input.txt`, then `part1.txt`. Example `N` is read from `inputs/YYYY/day_N/exampleN.txt`,
+/// or the checked-in `examples/YYYY/day_N/exampleN.txt`.
+pub fn input_candidates(day: CalendarDay, part: Part, example: Option<u8>) -> Vec<PathBuf> {
+    let dir = Path::new("inputs").join(day_dir(day));
+    let candidates = match example {
+        Some(n) => vec![
+            dir.join(format!("example{}.txt", n)),
+            Path::new("examples")
+                .join(day_dir(day))
+                .join(format!("example{}.txt", n)),
+        ],
+        None => vec![
+            dir.join(format!("part{}.txt", part.number())),
+            dir.join("input.txt"),
+            dir.join("part1.txt"),
+        ],
+    };
+    let mut unique = vec![];
+    for candidate in candidates {
+        if !unique.contains(&candidate) {
+            unique.push(candidate);
+        }
+    }
+    unique
+}
+
+/// Picks the first of the day/part's candidate input files that exists under `root`.
+pub fn find_input(
+    root: &Path,
+    day: CalendarDay,
+    part: Part,
+    example: Option<u8>,
+) -> Result<InputSource, MissingInputError> {
+    let searched: Vec<PathBuf> = input_candidates(day, part, example)
+        .into_iter()
+        .map(|candidate| root.join(candidate))
         .collect();
+    match searched.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(InputSource::File(path.clone())),
+        None => Err(MissingInputError {
+            day,
+            part,
+            searched,
+        }),
+    }
+}
+
+pub fn read_input(source: &InputSource) -> Result<String, io::Error> {
+    match source {
+        InputSource::File(path) => fs::read_to_string(path),
+        InputSource::Stdin => io::read_to_string(io::stdin()),
+    }
+}
+
+/// A day's puzzle input, along with where it was read from.
+#[derive(Clone)]
+pub struct PuzzleInput {
+    pub source: InputSource,
+    pub contents: String,
+    /// The SHA-256 of the contents, as lowercase hex
+    pub hash: String,
+}
 
-    Ok(lines)
+impl PuzzleInput {
+    pub fn new(source: InputSource, contents: String) -> Self {
+        let hash = format!("{:x}", Sha256::digest(contents.as_bytes()));
+        Self {
+            source,
+            contents,
+            hash,
+        }
+    }
 }
 
-pub fn raw_load_from_file(path: &str) -> Result<String, io::Error> {
-    Ok(fs::read_to_string(path)?.trim().to_string())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::models::{Day, Year};
+
+    #[test]
+    fn test_puzzle_input_hash() {
+        let input = PuzzleInput::new(InputSource::Stdin, "abc".to_string());
+        assert_eq!(
+            input.hash,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_find_input() {
+        let root = std::env::temp_dir().join(format!("aoc_find_input_{}", std::process::id()));
+        let dir = root.join("inputs/2025/day_3");
+        fs::create_dir_all(&dir).unwrap();
+        let day = CalendarDay::new(Year::new(2025), Day::new(3));
+        let file = |name: &str| InputSource::File(dir.join(name));
+
+        fs::write(dir.join("input.txt"), "1").unwrap();
+        assert_eq!(
+            find_input(&root, day, Part::Part2, None).unwrap(),
+            file("input.txt")
+        );
+
+        fs::write(dir.join("part2.txt"), "2").unwrap();
+        assert_eq!(
+            find_input(&root, day, Part::Part1, None).unwrap(),
+            file("input.txt")
+        );
+        assert_eq!(
+            find_input(&root, day, Part::Part2, None).unwrap(),
+            file("part2.txt")
+        );
+
+        let error = find_input(&root, day, Part::Part1, Some(2)).unwrap_err();
+        assert_eq!(
+            error.searched,
+            [
+                dir.join("example2.txt"),
+                root.join("examples/2025/day_3/example2.txt")
+            ]
+        );
+        assert!(error.to_string().contains("example2.txt"));
+        fs::remove_dir_all(&root).unwrap();
+    }
 }
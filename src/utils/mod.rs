@@ -1,6 +1,13 @@
 pub mod advent_day;
+pub mod answer;
 pub mod coordinates;
+pub mod diagnostic;
+#[cfg(test)]
+pub mod examples;
 pub mod grid;
 pub mod load;
 pub mod models;
+pub mod progress;
+pub mod registry;
 pub mod sets;
+pub mod visualize;
@@ -1,29 +1,251 @@
-#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
-pub enum Day {
+use crate::utils::registry;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The first year Advent of Code ran.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// An Advent of Code event, e.g. 2025.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Year(u16);
+
+impl Year {
+    pub const fn new(number: u16) -> Self {
+        Self(number)
+    }
+
+    pub fn number(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("Invalid year '{0}'. Should be a year Advent of Code ran, e.g. 2025")]
+pub struct ParseYearError(String);
+
+impl FromStr for Year {
+    type Err = ParseYearError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().parse::<u16>() {
+            Ok(number) if number >= FIRST_YEAR => Ok(Year(number)),
+            _ => Err(ParseYearError(s.into())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    pub const fn new(number: u8) -> Self {
+        Self(number)
+    }
+
+    pub fn number(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A day of one year's calendar, which is what identifies a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDay {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl CalendarDay {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for CalendarDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, clap::ValueEnum)]
+pub enum Part {
     #[clap(alias = "1")]
-    Day1,
+    Part1,
     #[clap(alias = "2")]
-    Day2,
-    #[clap(alias = "3")]
-    Day3,
-    #[clap(alias = "4")]
-    Day4,
-    #[clap(alias = "5")]
-    Day5,
-    #[clap(alias = "6")]
-    Day6,
-    #[clap(alias = "7")]
-    Day7,
-    #[clap(alias = "8")]
-    Day8,
-    #[clap(alias = "9")]
-    Day9,
+    Part2,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
-pub enum Part {
+pub enum PartSelection {
     #[clap(alias = "1")]
     Part1,
     #[clap(alias = "2")]
     Part2,
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(&self) -> Vec<Part> {
+        match self {
+            PartSelection::Part1 => vec![Part::Part1],
+            PartSelection::Part2 => vec![Part::Part2],
+            PartSelection::Both => vec![Part::Part1, Part::Part2],
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum ParseDaySelectionError {
+    /// Error when the selection isn't a list of days and ranges
+    #[error("Invalid day selection '{0}'. Should be a comma separated list of days or ranges, e.g. '1-5,8'")]
+    InvalidFormat(String),
+    /// Error when a day is selected that has no solver
+    #[error("Day {1} of {0} has no registered solver")]
+    Unregistered(Year, u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DaySpec {
+    Single(u8),
+    Range(RangeInclusive<u8>),
+}
+
+/// A set of days picked on the command line, e.g. `3` or `1-5,8`. Which days exist depends on
+/// the year, so the selection is checked against the registry by `resolve`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySelection(Vec<DaySpec>);
+
+impl FromStr for DaySelection {
+    type Err = ParseDaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_number = |raw: &str| {
+            let raw = raw.trim().to_lowercase();
+            raw.strip_prefix("day")
+                .unwrap_or(&raw)
+                .parse::<u8>()
+                .map_err(|_| ParseDaySelectionError::InvalidFormat(s.into()))
+        };
+        s.split(',')
+            .map(|token| match token.split_once('-') {
+                Some((start, end)) => Ok(DaySpec::Range(parse_number(start)?..=parse_number(end)?)),
+                None => Ok(DaySpec::Single(parse_number(token)?)),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl DaySelection {
+    /// The selected days of `year`, in order. Ranges only include the days with solvers, but
+    /// a day picked on its own must have one.
+    pub fn resolve(&self, year: Year) -> Result<Vec<Day>, ParseDaySelectionError> {
+        let registered = registry::days(year)
+            .into_iter()
+            .map(|registered| registered.day)
+            .collect_vec();
+        let mut days = vec![];
+        for spec in &self.0 {
+            match spec {
+                DaySpec::Range(range) => days.extend(
+                    registered
+                        .iter()
+                        .filter(|day| range.contains(&day.number())),
+                ),
+                DaySpec::Single(number) => {
+                    let day = Day::new(*number);
+                    if !registered.contains(&day) {
+                        return Err(ParseDaySelectionError::Unregistered(year, *number));
+                    }
+                    days.push(day);
+                }
+            }
+        }
+        Ok(days.into_iter().sorted().dedup().collect())
+    }
+}
+
+/// Where to read a day's puzzle input from: a file path, or `-` for stdin.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(path.into())),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_selection() {
+        let resolve = |s: &str| DaySelection::from_str(s).unwrap().resolve(Year::new(2025));
+        assert_eq!(
+            resolve("1-3,8,day9").unwrap(),
+            [1, 2, 3, 8, 9].map(Day::new)
+        );
+        assert_eq!(resolve("4,2,4").unwrap(), [2, 4].map(Day::new));
+        assert_eq!(resolve("0-3").unwrap(), [1, 2, 3].map(Day::new));
+
+        assert!(resolve("99").is_err());
+        assert!(DaySelection::from_str("1,,2").is_err());
+        assert!(DaySelection::from_str("3")
+            .unwrap()
+            .resolve(Year::new(2015))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(Year::from_str("2024").unwrap(), Year::new(2024));
+        assert!(Year::from_str("2014").is_err());
+        assert!(Year::from_str("twenty").is_err());
+    }
 }
@@ -0,0 +1,188 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Every task that's still reporting progress, for whatever is displaying it.
+static TASKS: Mutex<Vec<Weak<Task>>> = Mutex::new(vec![]);
+
+thread_local! {
+    /// Set while this thread runs a part that can be cancelled.
+    static CANCEL: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` as a part that's cancelled once `flag` is set. Jobs started from this thread
+/// while `f` runs see the cancellation through [`Progress::cancelled`].
+pub fn cancellable<T>(flag: Arc<AtomicBool>, f: impl FnOnce() -> T) -> T {
+    let previous = CANCEL.with(|cancel| cancel.replace(Some(flag)));
+    let result = f();
+    CANCEL.with(|cancel| *cancel.borrow_mut() = previous);
+    result
+}
+
+/// Error for a job that stopped early because its part was cancelled, e.g. after running out
+/// of time.
+#[derive(Debug, Error, Clone)]
+#[error("Cancelled")]
+pub struct Cancelled;
+
+struct Task {
+    unit: String,
+    total: u64,
+    done: AtomicU64,
+    started: Instant,
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+/// Reports how far through a long-running job a solver is, e.g. "1.2M of 12M rectangles
+/// checked". A `&Progress` can be shared between threads, but they all count on the same
+/// atomic, so hot loops should count in batches rather than per item. The job counts as
+/// finished once this is dropped.
+///
+/// Long jobs should also check [`cancelled`](Self::cancelled) as they go, and give up with
+/// [`Cancelled`] once their part has been, so they don't keep a thread busy after it has
+/// timed out.
+///
+/// Nothing is shown unless the runner is displaying progress.
+pub struct Progress {
+    task: Arc<Task>,
+}
+
+impl Progress {
+    /// Starts reporting a job of `total` items, described by `unit`.
+    pub fn new(total: u64, unit: impl Into<String>) -> Self {
+        let task = Arc::new(Task {
+            unit: unit.into(),
+            total,
+            done: AtomicU64::new(0),
+            started: Instant::now(),
+            cancel: CANCEL.with(|cancel| cancel.borrow().clone()),
+        });
+        let mut tasks = TASKS.lock().unwrap_or_else(|e| e.into_inner());
+        tasks.retain(|task| task.strong_count() > 0);
+        tasks.push(Arc::downgrade(&task));
+        Self { task }
+    }
+
+    /// Counts `n` more items as done.
+    pub fn inc(&self, n: u64) {
+        self.task.done.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Whether the part this job was started for has been cancelled.
+    pub fn cancelled(&self) -> bool {
+        self.task
+            .cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    /// Fails with [`Cancelled`] if the part this job was started for has been cancelled.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// How far a job had got when it was looked at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub unit: String,
+    pub done: u64,
+    pub total: u64,
+    pub elapsed: Duration,
+}
+
+impl Snapshot {
+    /// From 0 to 1. Jobs can overshoot their total, e.g. when a parallel search checks a few
+    /// more items after finding its answer, so this is capped.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.done as f64 / self.total as f64).min(1.0)
+    }
+}
+
+/// Every job still in progress, oldest first.
+pub fn active() -> Vec<Snapshot> {
+    let tasks = TASKS.lock().unwrap_or_else(|e| e.into_inner());
+    tasks
+        .iter()
+        .filter_map(Weak::upgrade)
+        .map(|task| Snapshot {
+            unit: task.unit.clone(),
+            done: task.done.load(Ordering::Relaxed),
+            total: task.total,
+            elapsed: task.started.elapsed(),
+        })
+        .collect()
+}
+
+/// A count shortened to a few digits, e.g. 1.2M.
+pub fn format_count(count: u64) -> String {
+    const UNITS: [&str; 4] = ["K", "M", "B", "T"];
+    if count < 1000 {
+        return count.to_string();
+    }
+    let mut value = count as f64;
+    let mut unit = "";
+    for next in UNITS {
+        if value < 1000.0 {
+            break;
+        }
+        value /= 1000.0;
+        unit = next;
+    }
+    if value < 10.0 {
+        format!("{:.1}{}", value, unit)
+    } else {
+        format!("{:.0}{}", value, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(unit: &str) -> Option<Snapshot> {
+        active().into_iter().find(|snapshot| snapshot.unit == unit)
+    }
+
+    #[test]
+    fn test_progress_is_reported_until_dropped() {
+        let progress = Progress::new(40, "test widgets");
+        progress.inc(10);
+        progress.inc(5);
+        let snapshot = find("test widgets").unwrap();
+        assert_eq!((snapshot.done, snapshot.total), (15, 40));
+        assert_eq!(snapshot.fraction(), 0.375);
+        drop(progress);
+        assert!(find("test widgets").is_none());
+    }
+
+    #[test]
+    fn test_progress_sees_cancellation() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let progress = cancellable(Arc::clone(&flag), || Progress::new(10, "test cancels"));
+        assert!(progress.check().is_ok());
+        flag.store(true, Ordering::Relaxed);
+        assert!(progress.cancelled());
+        assert!(progress.check().is_err());
+        // Jobs outside a cancellable part are never cancelled
+        assert!(!Progress::new(10, "test cancels").cancelled());
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_234), "1.2K");
+        assert_eq!(format_count(1_234_567), "1.2M");
+        assert_eq!(format_count(12_000_000), "12M");
+        assert_eq!(format_count(250_000_000_000), "250B");
+    }
+}
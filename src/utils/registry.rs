@@ -0,0 +1,89 @@
+use crate::utils::advent_day::Solver;
+use crate::utils::models::{CalendarDay, Day, Year};
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+/// A day's solver, registered from its own module with `inventory::submit!`:
+///
+/// ```ignore
+/// inventory::submit! {
+///     RegisteredDay::new(2025, 1, "Secret Entrance", &DayOne)
+/// }
+/// ```
+pub struct RegisteredDay {
+    pub year: Year,
+    pub day: Day,
+    pub name: &'static str,
+    pub solver: &'static dyn Solver,
+}
+
+impl RegisteredDay {
+    pub const fn new(year: u16, day: u8, name: &'static str, solver: &'static dyn Solver) -> Self {
+        Self {
+            year: Year::new(year),
+            day: Day::new(day),
+            name,
+            solver,
+        }
+    }
+
+    pub fn calendar_day(&self) -> CalendarDay {
+        CalendarDay::new(self.year, self.day)
+    }
+}
+
+inventory::collect!(RegisteredDay);
+
+/// Every year with at least one registered day, in order.
+pub fn years() -> Vec<Year> {
+    inventory::iter::<RegisteredDay>
+        .into_iter()
+        .map(|registered| registered.year)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// The most recent year with a registered day, which is run unless another is picked.
+pub fn latest_year() -> Option<Year> {
+    years().last().copied()
+}
+
+/// Every registered day of `year`, in day order.
+pub fn days(year: Year) -> Vec<&'static RegisteredDay> {
+    inventory::iter::<RegisteredDay>
+        .into_iter()
+        .filter(|registered| registered.year == year)
+        .sorted_by_key(|registered| registered.day)
+        .collect()
+}
+
+pub fn find(day: CalendarDay) -> Option<&'static RegisteredDay> {
+    inventory::iter::<RegisteredDay>
+        .into_iter()
+        .find(|registered| registered.calendar_day() == day)
+}
+
+/// The registered solver for `day`, or an error naming the missing day.
+pub fn solver(day: CalendarDay) -> Result<&'static dyn Solver> {
+    find(day)
+        .map(|registered| registered.solver)
+        .with_context(|| format!("Day {} of {} has no registered solver", day.day, day.year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_registered_once_in_order() {
+        let numbers = days(Year::new(2025))
+            .iter()
+            .map(|d| d.day.number())
+            .collect_vec();
+        assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(&numbers[..9], &(1..=9).collect_vec());
+        assert!(years().contains(&Year::new(2025)));
+        assert!(days(Year::new(2015)).is_empty());
+    }
+}
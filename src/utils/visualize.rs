@@ -0,0 +1,155 @@
+use crate::utils::advent_day::{AdventDay, ParsedInput};
+use crate::utils::models::{CalendarDay, Day, Part, Year};
+use anyhow::Result;
+use itertools::Itertools;
+use std::fmt;
+use std::fmt::Display;
+
+/// A colour, as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// One step of a simulation, as a grid of characters. Each character is drawn as a block of
+/// its [`colour`] in images.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// What happened in this step, e.g. "Wave 2". Images don't show it
+    pub caption: String,
+    /// The solver's running totals after this step, e.g. ("removed", "12"). Images don't show
+    /// these either
+    pub counters: Vec<(String, String)>,
+    pub rows: Vec<Vec<char>>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, rows: Vec<Vec<char>>) -> Self {
+        Self {
+            caption: caption.into(),
+            counters: vec![],
+            rows,
+        }
+    }
+
+    pub fn with_counter(mut self, name: impl Into<String>, value: impl Display) -> Self {
+        self.counters.push((name.into(), value.to_string()));
+        self
+    }
+
+    pub fn from_lines<S: AsRef<str>>(caption: impl Into<String>, lines: &[S]) -> Self {
+        let rows = lines
+            .iter()
+            .map(|line| line.as_ref().chars().collect())
+            .collect();
+        Self::new(caption, rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.caption)?;
+        if !self.counters.is_empty() {
+            let counters = self
+                .counters
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .join(", ");
+            write!(f, "\n{}", counters)?;
+        }
+        for row in &self.rows {
+            write!(f, "\n{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// The colour a cell is drawn in. Days share one palette, so the same character means the
+/// same thing everywhere: ` ` is outside the puzzle, `.` is empty space, `@`/`#` is something
+/// present, `x` is something removed, `|` is a path, and `o`/`S` is a start or target.
+pub fn colour(cell: char) -> Rgb {
+    match cell {
+        ' ' => [12, 12, 18],
+        '.' => [40, 40, 60],
+        '@' | '#' => [250, 200, 60],
+        'x' => [200, 60, 60],
+        '|' => [90, 200, 250],
+        '^' => [235, 235, 235],
+        'o' | 'S' => [110, 230, 110],
+        _ => [160, 160, 160],
+    }
+}
+
+/// A day that can show how it solves a part as a series of frames, written out with
+/// `--visualize`.
+pub trait Visualize: AdventDay {
+    fn frames(&self, part: Part, input: &Self::Input) -> Result<Vec<Frame>>;
+}
+
+/// The object-safe form of [`Visualize`], like [`Solver`](crate::utils::advent_day::Solver)
+/// is of [`AdventDay`].
+pub trait Visualizer: Sync {
+    /// The frames for an input parsed by this day's solver. Panics if it was parsed by
+    /// another day.
+    fn frames(&self, part: Part, input: &ParsedInput) -> Result<Vec<Frame>>;
+}
+
+impl<T: Visualize> Visualizer for T {
+    fn frames(&self, part: Part, input: &ParsedInput) -> Result<Vec<Frame>> {
+        let input = input
+            .downcast_ref::<T::Input>()
+            .expect("input was parsed by a different day");
+        Visualize::frames(self, part, input)
+    }
+}
+
+/// A day's visualization, registered next to its solver:
+///
+/// ```ignore
+/// inventory::submit! {
+///     RegisteredVisualization::new(2025, 4, &DayFour)
+/// }
+/// ```
+pub struct RegisteredVisualization {
+    pub year: Year,
+    pub day: Day,
+    pub visualizer: &'static dyn Visualizer,
+}
+
+impl RegisteredVisualization {
+    pub const fn new(year: u16, day: u8, visualizer: &'static dyn Visualizer) -> Self {
+        Self {
+            year: Year::new(year),
+            day: Day::new(day),
+            visualizer,
+        }
+    }
+}
+
+inventory::collect!(RegisteredVisualization);
+
+pub fn find(day: CalendarDay) -> Option<&'static dyn Visualizer> {
+    inventory::iter::<RegisteredVisualization>
+        .into_iter()
+        .find(|registered| CalendarDay::new(registered.year, registered.day) == day)
+        .map(|registered| registered.visualizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::from_lines("Start", &["..@", ".@"]);
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.to_string(), "Start\n..@\n.@");
+        let frame = frame.with_counter("rolls", 2).with_counter("removed", 0);
+        assert_eq!(frame.to_string(), "Start\nrolls: 2, removed: 0\n..@\n.@");
+    }
+}
@@ -0,0 +1,53 @@
+use advent_of_code_25::days::y2025::day_8::run::DayEight;
+use advent_of_code_25::runner::inputs::InputSelection;
+use advent_of_code_25::runner::run_days;
+use advent_of_code_25::utils::advent_day::AdventDay;
+use advent_of_code_25::utils::coordinates::{Coordinate3D, Distance};
+use advent_of_code_25::utils::grid::Grid;
+use advent_of_code_25::utils::models::{PartSelection, Year};
+use advent_of_code_25::utils::registry;
+use std::fs;
+
+#[test]
+fn test_days_are_registered_for_other_crates() {
+    let numbers: Vec<u8> = registry::days(Year::new(2025))
+        .iter()
+        .map(|d| d.day.number())
+        .collect();
+    assert_eq!(&numbers[..9], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn test_solver_from_another_crate() {
+    let input = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/2025/day_8/example1.txt"
+    ))
+    .unwrap();
+    let playground = DayEight.parse(&input).unwrap();
+    assert_eq!(DayEight.part_2(&playground).unwrap().to_string(), "25272");
+}
+
+#[test]
+fn test_utils() {
+    let grid = Grid::new(2, vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(grid.transpose(0).values, [[1, 3], [2, 4]]);
+
+    let a = Coordinate3D { x: 0, y: 0, z: 0 };
+    let b = Coordinate3D { x: 2, y: 3, z: 6 };
+    assert_eq!(a.distance_to(b), 7.0);
+}
+
+#[test]
+fn test_runner_from_another_crate() {
+    let selection = InputSelection {
+        days: Some("1-2".parse().unwrap()),
+        parts: PartSelection::Both,
+        input: None,
+        example: Some(1),
+    };
+    let year = Year::new(2025);
+    let runs = run_days(year, &selection.load(year).unwrap(), None).unwrap();
+    let answers: Vec<String> = runs.iter().map(|run| run.answer_text()).collect();
+    assert_eq!(answers, ["3", "6", "1227775554", "4174379265"]);
+}